@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::BufWriter;
+
+use omnixius::layers::l0_quantum::L0QuantumMutator;
+use omnixius::layers::l1_chronos::L1ChronosFileStorage;
+use omnixius::layers::l3_organisms::o4_day_mohk::benchmarks::{Benchmark, BenchmarkRun, ConvergenceCurve};
+use omnixius::layers::l3_organisms::o4_day_mohk::optimizers::OptimizerKind;
+use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::EvolutionConfig;
+
+/// Run the benchmark suite with the GA (fixed and self-adaptive mutator),
+/// differential evolution, CMA-ES and simulated annealing and write the
+/// convergence curves to CSV (default `benchmarks.csv`).
+fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| "benchmarks.csv".to_string());
+    let fixed = L0QuantumMutator::default();
+    let variants = [
+        ("fixed", fixed.clone(), OptimizerKind::Genetic),
+        (
+            "self_adaptive",
+            L0QuantumMutator { self_adaptive: true, ..L0QuantumMutator::default() },
+            OptimizerKind::Genetic,
+        ),
+        ("de", fixed.clone(), OptimizerKind::DifferentialEvolution { weight: 0.5, crossover_rate: 0.9 }),
+        ("cma_es", fixed.clone(), OptimizerKind::CmaEs { sigma: 0.3 }),
+        ("annealing", fixed, OptimizerKind::SimulatedAnnealing { initial_temperature: 1.0, cooling: 0.97 }),
+    ];
+
+    let mut curves = Vec::new();
+    for benchmark in Benchmark::suite(10, 42) {
+        for (variant, quantum, optimizer) in &variants {
+            let run = BenchmarkRun {
+                variant: variant.to_string(),
+                generations: 200,
+                seed: 42,
+                config: EvolutionConfig { elitism: 1, optimizer: optimizer.clone(), ..EvolutionConfig::default() },
+                ..BenchmarkRun::new(benchmark.clone())
+            };
+            let curve = run.run(quantum.clone(), L1ChronosFileStorage::new(std::env::temp_dir()));
+            let last = curve.points.last().expect("generation 0 is always recorded");
+            println!(
+                "{:<10} {:<13} | best {:>10.4} | optimum {:>10.4} | error {:>10.4} | solved at {:?}",
+                curve.benchmark,
+                curve.variant,
+                last.best_objective,
+                curve.optimum,
+                last.error,
+                curve.solved_at(1e-3)
+            );
+            curves.push(curve);
+        }
+    }
+
+    let file = File::create(&path).unwrap_or_else(|e| panic!("cannot create {path}: {e}"));
+    ConvergenceCurve::write_csv(&curves, BufWriter::new(file)).unwrap_or_else(|e| panic!("write failed: {e}"));
+    println!("convergence curves written to {path}");
+}
@@ -1,36 +1,12 @@
 use omnixius::layers::l0_quantum::L0QuantumMutator;
 use omnixius::layers::l1_chronos::L1ChronosFileStorage;
+use omnixius::layers::l3_organisms::o4_day_mohk::fitness::TargetPeakFitness;
 use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::{
     Dna, Organism, OrganismId, PhoenixEngine,
 };
-
-fn evaluate_fitness(dna: &Dna) -> f32 {
-    // Demo fitness: prefer mid-to-high genes with slight penalty for extreme values.
-    // Range: roughly [0..genes_len]
-    dna.genes
-        .iter()
-        .map(|&g| {
-            // peak around 0.8, mild penalty near 0.0 or 1.0
-            let target = 0.8_f32;
-            1.0 - (g - target).abs()
-        })
-        .sum::<f32>()
-        .max(0.0)
-}
-
-fn population_stats(pop: &[Organism]) -> (f32, f32, f32) {
-    let n = pop.len().max(1) as f32;
-    let best = pop
-        .iter()
-        .map(|o| o.fitness)
-        .fold(f32::NEG_INFINITY, f32::max);
-    let worst = pop
-        .iter()
-        .map(|o| o.fitness)
-        .fold(f32::INFINITY, f32::min);
-    let mean = pop.iter().map(|o| o.fitness).sum::<f32>() / n;
-    (best, mean, worst)
-}
+use omnixius::layers::l3_organisms::o4_day_mohk::run_controller::{
+    RunController, TerminationCriteria,
+};
 
 fn main() {
     let mut rng = rand::thread_rng();
@@ -39,6 +15,7 @@ fn main() {
     let quantum = L0QuantumMutator {
         mutation_rate: 0.10,
         sigma: 0.15,
+        self_adaptive: true,
     };
 
     // L1: chronos storage (file-backed, versioned JSON snapshots)
@@ -48,31 +25,44 @@ fn main() {
     // Initial population
     let population_size = 32usize;
     let dna_len = 12usize;
-    let mut population: Vec<Organism> = (0..population_size)
-        .map(|i| Organism {
-            id: OrganismId(i as u64),
-            dna: Dna::new_random(dna_len, &mut rng),
-            fitness: 0.0,
-        })
+    let population: Vec<Organism> = (0..population_size)
+        .map(|i| Organism::new(OrganismId(i as u64), Dna::new_random(dna_len, &mut rng)))
         .collect();
 
-    // Evaluate initial fitness
-    for o in &mut population {
-        o.fitness = evaluate_fitness(&o.dna);
-    }
-
-    // L3: Phoenix Engine
-    let mut engine = PhoenixEngine::new("L3_organisms::O4_day_mohk", quantum, blockchain, population);
+    // L3: Phoenix Engine (scores the initial population itself)
+    let mut engine = PhoenixEngine::new(
+        "L3_organisms::O4_day_mohk",
+        quantum,
+        blockchain,
+        TargetPeakFitness::default(),
+        population,
+    );
 
     println!("== OMNIXIUS Demo: Phoenix Engine ==");
-    println!("population={}, dna_len={}", engine.population_size(), dna_len);
+    println!(
+        "population={}, dna_len={}, seed={}",
+        engine.population_size(),
+        dna_len,
+        engine.seed()
+    );
 
+    // Stop at 8 generations, or earlier once every gene sits near the peak.
     let generations = 8u64;
-    for g in 0..generations {
-        let (best, mean, worst) = population_stats(&engine.population);
+    let mut controller = RunController::new(
+        TerminationCriteria {
+            max_generations: Some(generations),
+            target_fitness: Some(0.98 * dna_len as f32),
+            ..TerminationCriteria::default()
+        },
+        &engine,
+    )
+    .with_offspring_count(population_size);
+
+    for g in 0.. {
+        let stats = engine.stats();
         println!(
-            "gen {:>2} | best {:>7.3} | mean {:>7.3} | worst {:>7.3}",
-            g, best, mean, worst
+            "gen {:>2} | best {:>7.3} | mean {:>7.3} | worst {:>7.3} | diversity {:.3} | unique {}",
+            g, stats.best, stats.mean, stats.worst, stats.diversity, stats.unique_genotypes
         );
 
         // Save checkpoint at generation 0 and mid-run.
@@ -84,12 +74,10 @@ fn main() {
             println!("  checkpoint saved: {checkpoint_id}");
         }
 
-        // Evolve (creates offspring with fitness=0.0)
-        engine.evolve(&mut rng, population_size);
-
-        // Re-evaluate fitness after evolution
-        for o in &mut engine.population {
-            o.fitness = evaluate_fitness(&o.dna);
+        // Evolve (offspring are scored by the engine)
+        if let Some(reason) = controller.step(&mut engine) {
+            println!("run stopped: {reason:?} after {} evaluations", engine.evaluations());
+            break;
         }
     }
 
@@ -99,13 +87,13 @@ fn main() {
         .recover_from_blockchain("demo_gen_0")
         .unwrap_or_else(|e| panic!("recovery failed: {e}"));
     println!(
-        "recovered checkpoint demo_gen_0 | generation counter still = {} | pop={}",
+        "recovered checkpoint demo_gen_0 | generation restored to {} | pop={}",
         engine.generation,
         engine.population_size()
     );
-    let (best, mean, worst) = population_stats(&engine.population);
+    let stats = engine.stats();
     println!(
         "after recovery | best {:>7.3} | mean {:>7.3} | worst {:>7.3}",
-        best, mean, worst
+        stats.best, stats.mean, stats.worst
     );
 }
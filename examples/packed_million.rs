@@ -0,0 +1,56 @@
+use std::time::Instant;
+
+use omnixius::layers::l0_quantum::L0QuantumMutator;
+use omnixius::layers::l1_chronos::L1ChronosFileStorage;
+use omnixius::layers::l3_organisms::o4_day_mohk::evaluation::ParallelEvaluation;
+use omnixius::layers::l3_organisms::o4_day_mohk::fitness::TargetPeakFitness;
+use omnixius::layers::l3_organisms::o4_day_mohk::packed::{GeneEncoding, PackedPopulation};
+use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::{
+    EvolutionConfig, FitnessEvaluator, PhoenixEngine,
+};
+
+/// Evolve one million packed organisms (run with `--release`).
+fn main() {
+    let population_size = 1_000_000usize;
+    let dna_len = 16usize;
+    let generations = 5;
+    let fitness = TargetPeakFitness::default();
+
+    let mut rng = rand::thread_rng();
+    let started = Instant::now();
+    let mut population = PackedPopulation::random(population_size, dna_len, GeneEncoding::U8, &mut rng);
+    population.evaluate_from(0, &fitness as &dyn FitnessEvaluator, ParallelEvaluation::default());
+    println!("== OMNIXIUS Demo: packed population ==");
+    println!(
+        "population={}, dna_len={}, genes={} MiB ({:?}), created in {:.2?}",
+        population.len(),
+        dna_len,
+        population.gene_bytes() / (1 << 20),
+        population.encoding(),
+        started.elapsed()
+    );
+
+    let mut engine = PhoenixEngine::new(
+        "L3_organisms::O4_day_mohk",
+        L0QuantumMutator::default(),
+        L1ChronosFileStorage::new(std::env::temp_dir()),
+        fitness,
+        Vec::new(),
+    )
+    .with_config(EvolutionConfig { elitism: 1, ..EvolutionConfig::default() });
+
+    for _ in 0..generations {
+        let started = Instant::now();
+        engine.evolve_packed(&mut population).expect("fixed-length crossover");
+        let stats = &engine.history.last().expect("generation recorded").stats;
+        println!(
+            "gen {:>2} | {:>7.2?} | best {:>7.3} | mean {:>7.3} | diversity {:.3} | unique {}",
+            engine.generation,
+            started.elapsed(),
+            stats.best,
+            stats.mean,
+            stats.diversity,
+            stats.unique_genotypes
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use omnixius::layers::l0_quantum::L0QuantumMutator;
+use omnixius::layers::l1_chronos::L1ChronosFileStorage;
+use omnixius::layers::l3_organisms::o4_day_mohk::crossover::{AlignedCrossover, Crossover};
+use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::{
+    Dna, EvolutionConfig, Organism, OrganismId, PhoenixEngine,
+};
+use omnixius::layers::l3_organisms::o4_day_mohk::structural::StructuralMutation;
+
+/// Genome length the fitness asks for.
+const TARGET_LEN: usize = 16;
+const PEAK: f32 = 0.8;
+
+/// Closeness of the genes to `PEAK`, counted for at most `TARGET_LEN` genes,
+/// minus a penalty per gene of length mismatch. Growing is only worth it
+/// up to the target, so genomes settle there instead of bloating.
+fn shaped_fitness(genes: &[f32]) -> f32 {
+    let closeness: f32 = genes.iter().take(TARGET_LEN).map(|&g| 1.0 - (g - PEAK).abs()).sum();
+    closeness - genes.len().abs_diff(TARGET_LEN) as f32 * 0.5
+}
+
+fn main() {
+    let mut rng = rand::thread_rng();
+    let population: Vec<Organism> = (0..32)
+        .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+        .collect();
+
+    // Genomes start at 4 genes and may grow or shrink from there.
+    let mut engine = PhoenixEngine::new(
+        "L3_organisms::O4_day_mohk",
+        L0QuantumMutator::default(),
+        L1ChronosFileStorage::new(std::env::temp_dir().join("omnixius-structural-growth")),
+        shaped_fitness,
+        population,
+    )
+    .with_config(EvolutionConfig {
+        elitism: 2,
+        crossover: Crossover::Aligned(AlignedCrossover::default()),
+        structural: StructuralMutation {
+            insertion_rate: 0.1,
+            deletion_rate: 0.05,
+            duplication_rate: 0.05,
+            max_len: 32,
+            ..StructuralMutation::default()
+        },
+        ..EvolutionConfig::default()
+    });
+
+    println!("== Variable-length genomes (target length {TARGET_LEN}) ==");
+    for g in 0..=60 {
+        if g % 10 == 0 {
+            let best = engine.population.iter().max_by(|a, b| a.fitness.total_cmp(&b.fitness)).unwrap();
+            let mean_len = engine.population.iter().map(|o| o.dna.genes.len()).sum::<usize>() as f32
+                / engine.population_size() as f32;
+            println!(
+                "gen {g:>2} | best {:>6.3} (len {:>2}) | mean len {mean_len:>5.2}",
+                best.fitness,
+                best.dna.genes.len()
+            );
+        }
+        engine.evolve(32);
+    }
+}
@@ -7,16 +7,20 @@
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
 
-use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, QuantumMutator};
+use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, MutationParams, QuantumMutator};
 
 /// Quantum-inspired mutation operator.
 ///
 /// - `mutation_rate`: probability of mutating each gene.
 /// - `sigma`: standard deviation of gaussian perturbation.
+/// - `self_adaptive`: if set, every genome carries its own rate and sigma
+///   (seeded from the values above) which mutate before its genes do, so
+///   selection tunes mutation strength along with the solution.
 #[derive(Clone, Debug)]
 pub struct L0QuantumMutator {
     pub mutation_rate: f32,
     pub sigma: f32,
+    pub self_adaptive: bool,
 }
 
 impl Default for L0QuantumMutator {
@@ -24,39 +28,84 @@ impl Default for L0QuantumMutator {
         Self {
             mutation_rate: 0.08,
             sigma: 0.12,
+            self_adaptive: false,
         }
     }
 }
 
 impl L0QuantumMutator {
+    /// Bounds that keep self-adapted strategies from collapsing or exploding.
+    const SIGMA_RANGE: (f32, f32) = (1e-4, 0.5);
+    const RATE_RANGE: (f32, f32) = (1e-3, 1.0);
+    /// Learning rate of the logistic update of the per-genome rate.
+    const RATE_GAMMA: f32 = 0.22;
+
     fn clamp01(x: f32) -> f32 {
-        x.max(0.0).min(1.0)
+        x.clamp(0.0, 1.0)
+    }
+
+    /// Mutate the genome's own strategy: log-normal step on sigma
+    /// (τ = 1/√n) and a logistic step on the rate.
+    fn adapt_strategy<R: Rng + ?Sized>(&self, dna: &mut Dna, rng: &mut R) -> MutationParams {
+        let mut strategy = dna.strategy.clone().unwrap_or(MutationParams {
+            mutation_rate: self.mutation_rate,
+            sigma: self.sigma,
+        });
+        let tau = 1.0 / (dna.genes.len() as f32).sqrt();
+        let unit = Normal::new(0.0_f32, 1.0).expect("unit normal");
+
+        let sigma = strategy.sigma * (tau * unit.sample(rng)).exp();
+        strategy.sigma = sigma.clamp(Self::SIGMA_RANGE.0, Self::SIGMA_RANGE.1);
+
+        let rate = strategy.mutation_rate.clamp(Self::RATE_RANGE.0, 1.0 - f32::EPSILON);
+        let odds = (1.0 - rate) / rate * (-Self::RATE_GAMMA * unit.sample(rng)).exp();
+        strategy.mutation_rate = (1.0 / (1.0 + odds)).clamp(Self::RATE_RANGE.0, Self::RATE_RANGE.1);
+
+        dna.strategy = Some(strategy.clone());
+        strategy
     }
 }
 
 impl QuantumMutator for L0QuantumMutator {
-    fn quantum_mutate(&self, dna: &mut Dna) {
+    fn quantum_mutate<R: Rng + ?Sized>(&self, dna: &mut Dna, rng: &mut R) {
         if dna.genes.is_empty() {
             return;
         }
 
+        let (mutation_rate, sigma) = if self.self_adaptive {
+            let strategy = self.adapt_strategy(dna, rng);
+            (strategy.mutation_rate, strategy.sigma)
+        } else {
+            (self.mutation_rate, self.sigma)
+        };
+
         // "Quantum noise" ≈ gaussian distribution.
         // If sigma is invalid, fall back to a small constant.
-        let sigma = if self.sigma.is_finite() && self.sigma > 0.0 {
-            self.sigma
+        let sigma = if sigma.is_finite() && sigma > 0.0 {
+            sigma
         } else {
             0.05
         };
         let normal = Normal::new(0.0, sigma as f64).unwrap_or_else(|_| Normal::new(0.0, 0.05).unwrap());
 
-        let mut rng = rand::thread_rng();
-
+        // All randomness comes from the caller's RNG so seeded runs replay exactly.
         for g in &mut dna.genes {
-            if rng.gen::<f32>() <= self.mutation_rate {
-                let delta = normal.sample(&mut rng) as f32;
+            if rng.gen::<f32>() <= mutation_rate {
+                let delta = normal.sample(rng) as f32;
                 *g = Self::clamp01(*g + delta);
             }
         }
     }
-}
 
+    fn parameters(&self) -> Option<MutationParams> {
+        Some(MutationParams {
+            mutation_rate: self.mutation_rate,
+            sigma: self.sigma,
+        })
+    }
+
+    fn set_parameters(&mut self, params: &MutationParams) {
+        self.mutation_rate = params.mutation_rate;
+        self.sigma = params.sigma;
+    }
+}
@@ -3,37 +3,412 @@
 //!
 //! For now this provides a local, deterministic storage backend (file-based),
 //! while remaining compatible with a future on-chain backend.
+//!
+//! In [`StorageMode::HashChained`] the backend behaves like a local,
+//! append-only chain: every stored snapshot becomes a new block whose ledger
+//! entry carries the snapshot's content hash, its generation and the hash of
+//! the previous block, and [`L1ChronosFileStorage::verify_chain`] detects
+//! edited, missing or reordered checkpoints.
+//!
+//! Snapshots are written as JSON or, for large populations, in a compact
+//! binary format chosen by [`SnapshotCodec`]; loading detects the format
+//! from the file contents.
 
-use std::fs;
-use std::io;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{BlockchainStorage, Organism};
+use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{
+    BlockchainStorage, EngineSnapshot, EngineState, Organism,
+};
 
-/// File-backed storage for population snapshots (JSON).
+/// File-backed storage for population snapshots (JSON or binary).
 ///
 /// This is a practical stand-in for an on-chain backend: it gives us a stable,
 /// testable interface for L3 while L1's Move contracts evolve.
 #[derive(Clone, Debug)]
 pub struct L1ChronosFileStorage {
     root: PathBuf,
+    mode: StorageMode,
+    codec: SnapshotCodec,
+}
+
+/// Encoding of newly stored snapshots.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SnapshotCodec {
+    /// Pretty-printed JSON in `<checkpoint_id>.population.json`.
+    #[default]
+    Json,
+    /// MessagePack behind a binary header in `<checkpoint_id>.population.bin`.
+    /// (bincode cannot decode the engine's internally tagged config enums;
+    /// MessagePack is about as compact and self-describing.)
+    Binary { compression: Compression },
+}
+
+/// Compression of [`SnapshotCodec::Binary`] snapshots.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    None,
+    /// Fastest.
+    Lz4,
+    /// Smallest.
+    #[default]
+    Zstd,
+}
+
+/// Start of every binary snapshot: magic bytes, then one byte of header
+/// version and one of [`Compression`].
+const BINARY_MAGIC: &[u8; 4] = b"OMXC";
+const BINARY_HEADER_VERSION: u8 = 1;
+const ZSTD_LEVEL: i32 = 3;
+
+/// File name suffixes of plain checkpoints, by codec.
+const JSON_SUFFIX: &str = ".population.json";
+const BINARY_SUFFIX: &str = ".population.bin";
+
+impl SnapshotCodec {
+    /// Suffix of files written with this codec, then the other one.
+    fn suffixes(self) -> [&'static str; 2] {
+        match self {
+            SnapshotCodec::Json => [JSON_SUFFIX, BINARY_SUFFIX],
+            SnapshotCodec::Binary { .. } => [BINARY_SUFFIX, JSON_SUFFIX],
+        }
+    }
+}
+
+impl Compression {
+    fn id(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Lz4 => 1,
+            Compression::Zstd => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Compression::None),
+            1 => Some(Compression::Lz4),
+            2 => Some(Compression::Zstd),
+            _ => None,
+        }
+    }
+
+    fn compress(self, bytes: Vec<u8>) -> Result<Vec<u8>, L1ChronosError> {
+        Ok(match self {
+            Compression::None => bytes,
+            Compression::Lz4 => lz4_flex::compress_prepend_size(&bytes),
+            Compression::Zstd => zstd::encode_all(bytes.as_slice(), ZSTD_LEVEL)?,
+        })
+    }
+
+    fn decompress(self, bytes: &[u8]) -> Result<Vec<u8>, L1ChronosError> {
+        Ok(match self {
+            Compression::None => bytes.to_vec(),
+            Compression::Lz4 => lz4_flex::decompress_size_prepended(bytes)?,
+            Compression::Zstd => zstd::decode_all(bytes)?,
+        })
+    }
+}
+
+/// The versioned envelope inside a snapshot file.
+enum Payload<'a> {
+    Json(&'a [u8]),
+    MessagePack(Vec<u8>),
+}
+
+impl<'a> Payload<'a> {
+    /// Detect the file's format from its first bytes.
+    fn parse(bytes: &'a [u8]) -> Result<Self, L1ChronosError> {
+        let Some(header) = bytes.strip_prefix(BINARY_MAGIC) else {
+            return Ok(Payload::Json(bytes));
+        };
+        match header {
+            [BINARY_HEADER_VERSION, compression, body @ ..] => {
+                let compression = Compression::from_id(*compression).ok_or_else(|| {
+                    L1ChronosError::UnsupportedHeader(format!("unknown compression {compression}"))
+                })?;
+                Ok(Payload::MessagePack(compression.decompress(body)?))
+            }
+            [version, ..] => Err(L1ChronosError::UnsupportedHeader(format!("header version {version}"))),
+            [] => Err(L1ChronosError::UnsupportedHeader("truncated header".to_string())),
+        }
+    }
+
+    fn decode<T: DeserializeOwned>(&self) -> Result<T, L1ChronosError> {
+        match self {
+            Payload::Json(bytes) => Ok(serde_json::from_slice(bytes)?),
+            Payload::MessagePack(bytes) => Ok(rmp_serde::from_slice(bytes)?),
+        }
+    }
+}
+
+/// How [`L1ChronosFileStorage`] lays snapshots out on disk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMode {
+    /// One `<checkpoint_id>.population.json` file per checkpoint, replaced
+    /// when the checkpoint is stored again.
+    #[default]
+    Plain,
+    /// Append-only hash chain under `<root>/chain`: every store adds a block
+    /// (storing a checkpoint ID again supersedes, but keeps, the earlier
+    /// one). IDs resolve only through the ledger; plain files already under
+    /// the root are ignored until
+    /// [`import_plain_checkpoints`](L1ChronosFileStorage::import_plain_checkpoints).
+    HashChained,
+}
+
+/// Ledger entry of one block in [`StorageMode::HashChained`]. Hashes are
+/// hex-encoded SHA-256.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainBlock {
+    /// Position in the chain, from 0.
+    pub height: u64,
+    pub checkpoint_id: String,
+    /// Engine generation of the snapshot (0 for population-only snapshots).
+    pub generation: u64,
+    /// Hash of the stored snapshot file.
+    pub content_hash: String,
+    /// `hash` of the previous block ([`GENESIS_HASH`] for the first).
+    pub prev_hash: String,
+    /// Hash of all the fields above.
+    pub hash: String,
+}
+
+/// `prev_hash` of the first block.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+impl ChainBlock {
+    fn new(height: u64, checkpoint_id: String, generation: u64, content_hash: String, prev_hash: String) -> Self {
+        let mut block = Self { height, checkpoint_id, generation, content_hash, prev_hash, hash: String::new() };
+        block.hash = block.compute_hash();
+        block
+    }
+
+    fn compute_hash(&self) -> String {
+        let header = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            self.height, self.checkpoint_id, self.generation, self.content_hash, self.prev_hash
+        );
+        sha256_hex(header.as_bytes())
+    }
+}
+
+/// Summary of a successful [`L1ChronosFileStorage::verify_chain`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainReport {
+    pub blocks: u64,
+    /// Hash of the last block; anchoring it elsewhere also makes a truncated
+    /// chain detectable.
+    pub head: Option<String>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
 }
 
 impl L1ChronosFileStorage {
     pub fn new(root: impl Into<PathBuf>) -> Self {
-        Self { root: root.into() }
+        Self { root: root.into(), mode: StorageMode::Plain, codec: SnapshotCodec::Json }
     }
 
-    fn checkpoint_path(&self, checkpoint_id: &str) -> PathBuf {
-        // Keep filenames safe-ish. (No directories, no separators)
-        let safe = checkpoint_id
-            .chars()
-            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
-            .collect::<String>();
+    /// Use storage `mode` (builder style).
+    pub fn with_mode(mut self, mode: StorageMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Write new snapshots with `codec` (builder style). Snapshots in any
+    /// format stay loadable.
+    pub fn with_codec(mut self, codec: SnapshotCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
 
-        self.root.join(format!("{safe}.population.json"))
+    pub fn mode(&self) -> StorageMode {
+        self.mode
+    }
+
+    pub fn codec(&self) -> SnapshotCodec {
+        self.codec
+    }
+
+    /// Storage for engine `engine_id` of `tenant`, under
+    /// `<root>/tenants/<tenant>/<engine_id>`. The tenant is hex-encoded, so
+    /// distinct tenants never share a directory whatever their names.
+    pub fn tenant(&self, tenant: &str, engine_id: &str) -> Self {
+        let tenant: String = tenant.bytes().map(|b| format!("{b:02x}")).collect();
+        Self::new(self.root.join("tenants").join(tenant).join(safe_name(engine_id)))
+            .with_mode(self.mode)
+            .with_codec(self.codec)
+    }
+
+    /// IDs of the stored checkpoints, sorted (empty if nothing was stored
+    /// yet). Any of them can be recovered or forked.
+    pub fn checkpoint_ids(&self) -> Result<Vec<String>, L1ChronosError> {
+        let mut ids = match self.mode {
+            StorageMode::Plain => self.plain_ids()?,
+            StorageMode::HashChained => self.chain()?.into_iter().map(|block| block.checkpoint_id).collect(),
+        };
+        ids.sort();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    /// Append every plain checkpoint file under the root that the ledger
+    /// does not know yet as a block, e.g. after switching a storage to
+    /// [`StorageMode::HashChained`]. Returns the imported IDs; the files
+    /// stay in place. A no-op in plain mode.
+    pub fn import_plain_checkpoints(&mut self) -> Result<Vec<String>, L1ChronosError> {
+        if self.mode != StorageMode::HashChained {
+            return Ok(Vec::new());
+        }
+        let chained: Vec<String> = self.chain()?.into_iter().map(|block| block.checkpoint_id).collect();
+        let mut imported = Vec::new();
+        for id in self.plain_ids()? {
+            if chained.contains(&id) {
+                continue;
+            }
+            let bytes = self.read_plain(&id)?;
+            let generation = Self::decode_snapshot(&bytes)?.state.map_or(0, |state| state.generation);
+            self.append_block(&id, generation, &bytes)?;
+            imported.push(id);
+        }
+        Ok(imported)
+    }
+
+    /// IDs of the checkpoint files under the root (plain mode's layout).
+    fn plain_ids(&self) -> Result<Vec<String>, L1ChronosError> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut ids = Vec::new();
+        for entry in entries {
+            let name = entry?.file_name();
+            let name = name.to_str().unwrap_or_default();
+            if let Some(id) = name.strip_suffix(JSON_SUFFIX).or_else(|| name.strip_suffix(BINARY_SUFFIX)) {
+                ids.push(id.to_string());
+            }
+        }
+        ids.sort();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    /// Contents of a plain checkpoint file: the configured codec's first;
+    /// the other is from before a codec change.
+    fn read_plain(&self, checkpoint_id: &str) -> Result<Vec<u8>, L1ChronosError> {
+        for suffix in self.codec.suffixes() {
+            match fs::read(self.checkpoint_path(checkpoint_id, suffix)) {
+                Ok(bytes) => return Ok(bytes),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Err(L1ChronosError::NotFound(checkpoint_id.to_string()))
+    }
+
+    /// Blocks of the hash chain, in ledger order (empty if nothing was
+    /// stored in [`StorageMode::HashChained`]).
+    pub fn chain(&self) -> Result<Vec<ChainBlock>, L1ChronosError> {
+        let ledger = match fs::read_to_string(self.ledger_path()) {
+            Ok(ledger) => ledger,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        ledger
+            .lines()
+            .enumerate()
+            .map(|(line, entry)| {
+                serde_json::from_str(entry).map_err(|_| L1ChronosError::ChainBroken {
+                    height: line as u64,
+                    reason: "unreadable ledger entry".to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Check the whole hash chain: every block must follow its predecessor,
+    /// hash to its recorded value and point at an unmodified snapshot file.
+    /// Errors with [`L1ChronosError::ChainBroken`] at the first bad block.
+    pub fn verify_chain(&self) -> Result<ChainReport, L1ChronosError> {
+        let blocks = self.chain()?;
+        let mut prev_hash = GENESIS_HASH;
+        for (height, block) in (0u64..).zip(&blocks) {
+            let broken = |reason: &str| L1ChronosError::ChainBroken { height, reason: reason.to_string() };
+            if block.height != height {
+                return Err(broken(&format!("out of order (records height {})", block.height)));
+            }
+            if block.prev_hash != prev_hash {
+                return Err(broken("previous hash mismatch"));
+            }
+            if block.compute_hash() != block.hash {
+                return Err(broken("block hash mismatch"));
+            }
+            self.read_block(block)?;
+            prev_hash = &block.hash;
+        }
+        let height = blocks.len() as u64;
+        if self.block_path(height).exists() {
+            return Err(L1ChronosError::ChainBroken { height, reason: "snapshot missing from ledger".to_string() });
+        }
+        Ok(ChainReport { blocks: height, head: blocks.last().map(|block| block.hash.clone()) })
+    }
+
+    /// Contents of `block`'s snapshot file, checked against its content hash.
+    fn read_block(&self, block: &ChainBlock) -> Result<Vec<u8>, L1ChronosError> {
+        let broken = |reason: &str| L1ChronosError::ChainBroken { height: block.height, reason: reason.to_string() };
+        let bytes = match fs::read(self.block_path(block.height)) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(broken("snapshot file missing")),
+            Err(e) => return Err(e.into()),
+        };
+        if sha256_hex(&bytes) != block.content_hash {
+            return Err(broken("snapshot content edited"));
+        }
+        Ok(bytes)
+    }
+
+    /// Append `bytes` (an encoded snapshot at `generation`) as a new block.
+    fn append_block(&self, checkpoint_id: &str, generation: u64, bytes: &[u8]) -> Result<(), L1ChronosError> {
+        let (height, prev_hash) = match self.chain()?.pop() {
+            Some(last) => (last.height + 1, last.hash),
+            None => (0, GENESIS_HASH.to_string()),
+        };
+        let block = ChainBlock::new(height, safe_name(checkpoint_id), generation, sha256_hex(bytes), prev_hash);
+        fs::create_dir_all(self.root.join("chain"))?;
+        // The snapshot goes first: a crash in between leaves a file the
+        // ledger does not know about, which the next block replaces.
+        fs::write(self.block_path(height), bytes)?;
+        let mut entry = serde_json::to_string(&block)?;
+        entry.push('\n');
+        OpenOptions::new().create(true).append(true).open(self.ledger_path())?.write_all(entry.as_bytes())?;
+        Ok(())
+    }
+
+    fn ledger_path(&self) -> PathBuf {
+        self.root.join("chain").join("ledger.jsonl")
+    }
+
+    fn block_path(&self, height: u64) -> PathBuf {
+        self.root.join("chain").join(format!("{height:010}.snapshot"))
+    }
+
+    fn checkpoint_path(&self, checkpoint_id: &str, suffix: &str) -> PathBuf {
+        self.root.join(format!("{}{suffix}", safe_name(checkpoint_id)))
     }
 
     fn ensure_root(&self) -> Result<(), io::Error> {
@@ -41,6 +416,13 @@ impl L1ChronosFileStorage {
     }
 }
 
+/// Keep file and directory names safe-ish. (No separators, no dots.)
+fn safe_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum L1ChronosError {
     #[error("io error: {0}")]
@@ -49,6 +431,18 @@ pub enum L1ChronosError {
     Json(#[from] serde_json::Error),
     #[error("checkpoint not found: {0}")]
     NotFound(String),
+    #[error("unsupported snapshot version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("hash chain broken at block {height}: {reason}")]
+    ChainBroken { height: u64, reason: String },
+    #[error("messagepack encode error: {0}")]
+    MessagePackEncode(#[from] rmp_serde::encode::Error),
+    #[error("messagepack decode error: {0}")]
+    MessagePackDecode(#[from] rmp_serde::decode::Error),
+    #[error("lz4 error: {0}")]
+    Lz4(#[from] lz4_flex::block::DecompressError),
+    #[error("unsupported binary snapshot: {0}")]
+    UnsupportedHeader(String),
 }
 
 // We store exactly what L3 expects, but keep a versioned envelope for future migrations.
@@ -58,35 +452,327 @@ struct SnapshotV1 {
     population: Vec<Organism>,
 }
 
+// v2: adds the run seed so checkpoints can be tied back to a reproducible run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SnapshotV2 {
+    version: u32,
+    seed: u64,
+    population: Vec<Organism>,
+}
+
+// v3: full engine state (generation, RNG position, mutator params, config, history).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SnapshotV3 {
+    version: u32,
+    seed: u64,
+    state: EngineState,
+    population: Vec<Organism>,
+}
+
+#[derive(Deserialize)]
+struct SnapshotVersion {
+    version: u32,
+}
+
+impl From<SnapshotV1> for EngineSnapshot {
+    fn from(snap: SnapshotV1) -> Self {
+        EngineSnapshot {
+            seed: None,
+            population: snap.population,
+            state: None,
+        }
+    }
+}
+
+impl From<SnapshotV2> for EngineSnapshot {
+    fn from(snap: SnapshotV2) -> Self {
+        EngineSnapshot {
+            seed: Some(snap.seed),
+            population: snap.population,
+            state: None,
+        }
+    }
+}
+
+impl From<SnapshotV3> for EngineSnapshot {
+    fn from(snap: SnapshotV3) -> Self {
+        EngineSnapshot {
+            seed: Some(snap.seed),
+            population: snap.population,
+            state: Some(snap.state),
+        }
+    }
+}
+
+impl L1ChronosFileStorage {
+    const SNAPSHOT_VERSION: u32 = 3;
+
+    /// Decode any known format and envelope version, migrating older ones to
+    /// the current [`EngineSnapshot`] shape.
+    fn decode_snapshot(bytes: &[u8]) -> Result<EngineSnapshot, L1ChronosError> {
+        let payload = Payload::parse(bytes)?;
+        let SnapshotVersion { version } = payload.decode()?;
+        match version {
+            1 => Ok(payload.decode::<SnapshotV1>()?.into()),
+            2 => Ok(payload.decode::<SnapshotV2>()?.into()),
+            3 => Ok(payload.decode::<SnapshotV3>()?.into()),
+            other => Err(L1ChronosError::UnsupportedVersion(other)),
+        }
+    }
+
+    fn encode_snapshot(&self, snapshot: &EngineSnapshot) -> Result<Vec<u8>, L1ChronosError> {
+        // Snapshots produced by the engine always carry the run seed; partial
+        // ones (e.g. hand-built) are written in the older population-only shape.
+        match (snapshot.seed, &snapshot.state) {
+            (Some(seed), Some(state)) => self.encode_envelope(&SnapshotV3 {
+                version: Self::SNAPSHOT_VERSION,
+                seed,
+                state: state.clone(),
+                population: snapshot.population.clone(),
+            }),
+            (Some(seed), None) => self.encode_envelope(&SnapshotV2 {
+                version: 2,
+                seed,
+                population: snapshot.population.clone(),
+            }),
+            (None, _) => self.encode_envelope(&SnapshotV1 {
+                version: 1,
+                population: snapshot.population.clone(),
+            }),
+        }
+    }
+
+    fn encode_envelope<T: Serialize>(&self, envelope: &T) -> Result<Vec<u8>, L1ChronosError> {
+        match self.codec {
+            SnapshotCodec::Json => Ok(serde_json::to_vec_pretty(envelope)?),
+            SnapshotCodec::Binary { compression } => {
+                // Named fields keep `#[serde(default)]` migrations working.
+                let body = compression.compress(rmp_serde::to_vec_named(envelope)?)?;
+                let mut bytes = Vec::with_capacity(BINARY_MAGIC.len() + 2 + body.len());
+                bytes.extend_from_slice(BINARY_MAGIC);
+                bytes.extend_from_slice(&[BINARY_HEADER_VERSION, compression.id()]);
+                bytes.extend_from_slice(&body);
+                Ok(bytes)
+            }
+        }
+    }
+}
+
 impl BlockchainStorage for L1ChronosFileStorage {
     type Error = L1ChronosError;
 
-    fn load_population(&self, checkpoint_id: &str) -> Result<Vec<Organism>, Self::Error> {
-        let path = self.checkpoint_path(checkpoint_id);
-        if !Path::new(&path).exists() {
-            return Err(L1ChronosError::NotFound(checkpoint_id.to_string()));
+    fn load_snapshot(&self, checkpoint_id: &str) -> Result<EngineSnapshot, Self::Error> {
+        if self.mode == StorageMode::HashChained {
+            let id = safe_name(checkpoint_id);
+            return match self.chain()?.into_iter().rev().find(|block| block.checkpoint_id == id) {
+                Some(block) => Self::decode_snapshot(&self.read_block(&block)?),
+                None => Err(L1ChronosError::NotFound(checkpoint_id.to_string())),
+            };
         }
-
-        let bytes = fs::read(&path)?;
-        let snap: SnapshotV1 = serde_json::from_slice(&bytes)?;
-        Ok(snap.population)
+        Self::decode_snapshot(&self.read_plain(checkpoint_id)?)
     }
 
-    fn store_population(
+    fn store_snapshot(
         &mut self,
         checkpoint_id: &str,
-        population: &[Organism],
+        snapshot: &EngineSnapshot,
     ) -> Result<(), Self::Error> {
         self.ensure_root()?;
 
-        let path = self.checkpoint_path(checkpoint_id);
-        let snap = SnapshotV1 {
-            version: 1,
-            population: population.to_vec(),
-        };
-        let bytes = serde_json::to_vec_pretty(&snap)?;
-        fs::write(&path, bytes)?;
+        let bytes = self.encode_snapshot(snapshot)?;
+        if self.mode == StorageMode::HashChained {
+            let generation = snapshot.state.as_ref().map_or(0, |state| state.generation);
+            return self.append_block(checkpoint_id, generation, &bytes);
+        }
+        let [current, stale] = self.codec.suffixes();
+        fs::write(self.checkpoint_path(checkpoint_id, current), bytes)?;
+        // Drop the checkpoint's file from before a codec change, so the ID
+        // only ever resolves to the latest snapshot.
+        match fs::remove_file(self.checkpoint_path(checkpoint_id, stale)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Delete the checkpoint's files. A no-op in hash-chained mode: blocks
+    /// are part of the ledger and stay for verification.
+    fn remove_snapshot(&mut self, checkpoint_id: &str) -> Result<(), Self::Error> {
+        if self.mode == StorageMode::HashChained {
+            return Ok(());
+        }
+        for suffix in self.codec.suffixes() {
+            match fs::remove_file(self.checkpoint_path(checkpoint_id, suffix)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
         Ok(())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, OrganismId};
+
+    #[test]
+    fn v1_snapshots_migrate_to_current_shape() {
+        let root = std::env::temp_dir().join(format!("omnixius-l1-v1-{}", std::process::id()));
+        let mut storage = L1ChronosFileStorage::new(&root);
+        assert!(storage.checkpoint_ids().unwrap().is_empty());
+        storage.ensure_root().unwrap();
+
+        let v1 = r#"{ "version": 1, "population": [
+            { "id": 7, "dna": { "genes": [0.25, 0.5] }, "fitness": 1.5 }
+        ] }"#;
+        fs::write(storage.checkpoint_path("legacy", JSON_SUFFIX), v1).unwrap();
+
+        let snap = storage.load_snapshot("legacy").unwrap();
+        assert_eq!(snap.seed, None);
+        assert!(snap.state.is_none());
+        assert_eq!(snap.population.len(), 1);
+        assert_eq!(snap.population[0].dna.genes, vec![0.25, 0.5]);
+
+        // Re-storing a migrated snapshot keeps it loadable.
+        storage.store_snapshot("legacy", &snap).unwrap();
+        assert_eq!(storage.load_snapshot("legacy").unwrap().population.len(), 1);
+
+        let full = EngineSnapshot {
+            seed: Some(11),
+            population: snap.population,
+            state: Some(EngineState {
+                generation: 42,
+                rng_word_pos: u128::from(u64::MAX) + 5,
+                mutation: None,
+                mutation_scale: 1.0,
+                config: Default::default(),
+                history: Vec::new(),
+                evaluations: 0,
+                hall_of_fame: Default::default(),
+                rebirth_checkpoints: Vec::new(),
+                last_rebirth: None,
+                optimizer: None,
+                origin: None,
+            }),
+        };
+        storage.store_snapshot("full", &full).unwrap();
+        let state = storage.load_snapshot("full").unwrap().state.unwrap();
+        assert_eq!(state.generation, 42);
+        assert_eq!(state.rng_word_pos, u128::from(u64::MAX) + 5);
+        assert_eq!(storage.checkpoint_ids().unwrap(), vec!["full", "legacy"]);
+        storage.remove_snapshot("legacy").unwrap();
+        storage.remove_snapshot("legacy").unwrap();
+        assert_eq!(storage.checkpoint_ids().unwrap(), vec!["full"]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn hash_chain_detects_edited_missing_and_reordered_checkpoints() {
+        let root = std::env::temp_dir().join(format!("omnixius-l1-chain-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let mut storage = L1ChronosFileStorage::new(&root).with_mode(StorageMode::HashChained);
+        let snapshot = |genes: Vec<f32>| EngineSnapshot {
+            seed: Some(1),
+            population: vec![Organism::new(OrganismId(0), Dna::from_genes(genes))],
+            state: None,
+        };
+        // A plain file is outside the ledger until it is imported.
+        L1ChronosFileStorage::new(&root).store_snapshot("old", &snapshot(vec![0.4])).unwrap();
+        storage.store_snapshot("a", &snapshot(vec![0.1])).unwrap();
+        storage.store_snapshot("b", &snapshot(vec![0.2])).unwrap();
+        storage.store_snapshot("a", &snapshot(vec![0.3])).unwrap();
+        assert!(matches!(storage.load_snapshot("old"), Err(L1ChronosError::NotFound(_))));
+
+        // Storing an ID again supersedes the earlier block.
+        assert_eq!(storage.load_snapshot("a").unwrap().population[0].dna.genes, vec![0.3]);
+        assert_eq!(storage.checkpoint_ids().unwrap(), vec!["a", "b"]);
+        // Blocks are permanent: removing a checkpoint keeps it in the ledger.
+        storage.remove_snapshot("b").unwrap();
+        assert_eq!(storage.load_snapshot("b").unwrap().population[0].dna.genes, vec![0.2]);
+        let report = storage.verify_chain().unwrap();
+        assert_eq!(report.blocks, 3);
+        assert_eq!(report.head.as_ref(), Some(&storage.chain().unwrap()[2].hash));
+
+        let broken_at = |storage: &L1ChronosFileStorage| match storage.verify_chain() {
+            Err(L1ChronosError::ChainBroken { height, .. }) => height,
+            other => panic!("expected a broken chain, got {other:?}"),
+        };
+        let ledger = storage.ledger_path();
+        let entries = fs::read_to_string(&ledger).unwrap();
+        let block = storage.block_path(1);
+        let contents = fs::read(&block).unwrap();
+
+        fs::write(&block, String::from_utf8(contents.clone()).unwrap().replace("0.2", "0.9")).unwrap();
+        assert_eq!(broken_at(&storage), 1);
+        assert!(matches!(storage.load_snapshot("b"), Err(L1ChronosError::ChainBroken { height: 1, .. })));
+        fs::remove_file(&block).unwrap();
+        assert_eq!(broken_at(&storage), 1);
+        fs::write(&block, &contents).unwrap();
+
+        let lines: Vec<&str> = entries.lines().collect();
+        fs::write(&ledger, format!("{}\n{}\n{}\n", lines[1], lines[0], lines[2])).unwrap();
+        assert_eq!(broken_at(&storage), 0);
+        fs::write(&ledger, format!("{}\n{}\n", lines[0], lines[1])).unwrap();
+        assert_eq!(broken_at(&storage), 2);
+
+        fs::write(&ledger, &entries).unwrap();
+        assert_eq!(storage.verify_chain().unwrap(), report);
+
+        assert_eq!(storage.import_plain_checkpoints().unwrap(), vec!["old"]);
+        assert!(storage.import_plain_checkpoints().unwrap().is_empty());
+        assert_eq!(storage.load_snapshot("old").unwrap().population[0].dna.genes, vec![0.4]);
+        assert_eq!(storage.checkpoint_ids().unwrap(), vec!["a", "b", "old"]);
+        assert_eq!(storage.verify_chain().unwrap().blocks, 4);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn binary_snapshots_are_compact_and_detected_on_load() {
+        let root = std::env::temp_dir().join(format!("omnixius-l1-codec-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let snapshot = EngineSnapshot {
+            seed: Some(3),
+            population: (0..200)
+                .map(|i| Organism::new(OrganismId(i), Dna::from_genes(vec![i as f32 / 200.0; 16])))
+                .collect(),
+            state: Some(EngineState {
+                generation: 7,
+                rng_word_pos: u128::from(u64::MAX) + 9,
+                mutation: None,
+                mutation_scale: 0.5,
+                config: Default::default(),
+                history: Vec::new(),
+                evaluations: 1_400,
+                hall_of_fame: Default::default(),
+                rebirth_checkpoints: Vec::new(),
+                last_rebirth: None,
+                optimizer: None,
+                origin: None,
+            }),
+        };
+        let expected = serde_json::to_string(&snapshot).unwrap();
+        let mut json = L1ChronosFileStorage::new(&root);
+        json.store_snapshot("s", &snapshot).unwrap();
+        let json_len = fs::metadata(json.checkpoint_path("s", JSON_SUFFIX)).unwrap().len();
+
+        for compression in [Compression::None, Compression::Lz4, Compression::Zstd] {
+            let mut binary = json.clone().with_codec(SnapshotCodec::Binary { compression });
+            // Each storage reads the other's format.
+            let loaded = binary.load_snapshot("s").unwrap();
+            assert_eq!(serde_json::to_string(&loaded).unwrap(), expected);
+            binary.store_snapshot("s", &snapshot).unwrap();
+            assert!(!json.checkpoint_path("s", JSON_SUFFIX).exists());
+            let binary_len = fs::metadata(binary.checkpoint_path("s", BINARY_SUFFIX)).unwrap().len();
+            assert!(binary_len * 2 < json_len, "{compression:?}: {binary_len} vs {json_len} bytes");
+            assert_eq!(serde_json::to_string(&json.load_snapshot("s").unwrap()).unwrap(), expected);
+            assert_eq!(json.checkpoint_ids().unwrap(), vec!["s"]);
+            json.store_snapshot("s", &snapshot).unwrap();
+            assert!(!binary.checkpoint_path("s", BINARY_SUFFIX).exists());
+        }
+
+        fs::write(json.checkpoint_path("s", JSON_SUFFIX), b"OMXC\x09\x00").unwrap();
+        assert!(matches!(json.load_snapshot("s"), Err(L1ChronosError::UnsupportedHeader(_))));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,259 @@
+//! Layer: L1 – Chronos
+//! Module: Persistent evolution history (SQLite)
+//!
+//! Every generation's `HistoryPoint` is stored per run, so fitness curves
+//! survive restarts. Callers give every process run its own run ID: a
+//! generation stored again under the same run replaces the earlier one.
+//! Long runs are charted through [`HistoryStore::query`], which downsamples
+//! a generation range into min/max/mean buckets inside SQLite instead of
+//! transferring every row.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::HistoryPoint;
+
+/// Min/max/mean of one metric over a bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SeriesBucket {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+/// Aggregated history over `start_generation..=end_generation`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryBucket {
+    pub start_generation: u64,
+    pub end_generation: u64,
+    /// Number of stored generations in the bucket.
+    pub count: u64,
+    pub best_fitness: SeriesBucket,
+    pub mean_fitness: SeriesBucket,
+    pub worst_fitness: SeriesBucket,
+    pub diversity: SeriesBucket,
+}
+
+pub struct HistoryStore {
+    pool: SqlitePool,
+}
+
+/// Generations per bucket so that `from..=to` fits in `resolution` buckets.
+pub fn bucket_width(from: u64, to: u64, resolution: u64) -> u64 {
+    let span = to.saturating_sub(from) + 1;
+    span.div_ceil(resolution.max(1))
+}
+
+impl HistoryStore {
+    pub async fn new(pool: SqlitePool) -> Self {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS evolution_history (
+                run_id TEXT NOT NULL,
+                generation INTEGER NOT NULL,
+                best_fitness REAL NOT NULL,
+                mean_fitness REAL NOT NULL,
+                median_fitness REAL NOT NULL,
+                worst_fitness REAL NOT NULL,
+                std_dev REAL NOT NULL,
+                diversity REAL NOT NULL,
+                unique_genotypes INTEGER NOT NULL,
+                species_count INTEGER NOT NULL,
+                point TEXT NOT NULL,
+                PRIMARY KEY (run_id, generation)
+            )"
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to create evolution_history table");
+
+        Self { pool }
+    }
+
+    /// Store (or overwrite) one generation of `run_id`.
+    pub async fn record(&self, run_id: &str, point: &HistoryPoint) -> Result<(), String> {
+        let json = serde_json::to_string(point).map_err(|e| e.to_string())?;
+        let stats = &point.stats;
+        sqlx::query(
+            "INSERT OR REPLACE INTO evolution_history
+                (run_id, generation, best_fitness, mean_fitness, median_fitness, worst_fitness,
+                 std_dev, diversity, unique_genotypes, species_count, point)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(run_id)
+        .bind(point.generation as i64)
+        .bind(point.best_fitness as f64)
+        .bind(stats.mean as f64)
+        .bind(stats.median as f64)
+        .bind(stats.worst as f64)
+        .bind(stats.std_dev as f64)
+        .bind(stats.diversity as f64)
+        .bind(stats.unique_genotypes as i64)
+        .bind(point.species_count as i64)
+        .bind(json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// IDs of every stored run, sorted.
+    pub async fn runs(&self) -> Result<Vec<String>, String> {
+        let rows = sqlx::query("SELECT DISTINCT run_id FROM evolution_history ORDER BY run_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(rows.iter().map(|r| r.get(0)).collect())
+    }
+
+    /// Latest stored generation of `run_id`, if any.
+    pub async fn latest_generation(&self, run_id: &str) -> Result<Option<u64>, String> {
+        let row = sqlx::query("SELECT MAX(generation) FROM evolution_history WHERE run_id = ?")
+            .bind(run_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let latest: Option<i64> = row.get(0);
+        Ok(latest.map(|g| g as u64))
+    }
+
+    /// Full history points of `run_id` in `from..=to`, oldest first.
+    pub async fn points(&self, run_id: &str, from: u64, to: u64) -> Result<Vec<HistoryPoint>, String> {
+        let rows = sqlx::query(
+            "SELECT point FROM evolution_history
+             WHERE run_id = ? AND generation BETWEEN ? AND ?
+             ORDER BY generation"
+        )
+        .bind(run_id)
+        .bind(from as i64)
+        .bind(to as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        rows.iter()
+            .map(|r| serde_json::from_str(r.get::<&str, _>(0)).map_err(|e| e.to_string()))
+            .collect()
+    }
+
+    /// Downsample `from..=to` of `run_id` into at most `resolution` buckets
+    /// of equal generation width. Empty buckets are omitted.
+    pub async fn query(
+        &self,
+        run_id: &str,
+        from: u64,
+        to: u64,
+        resolution: u64,
+    ) -> Result<Vec<HistoryBucket>, String> {
+        if to < from {
+            return Ok(Vec::new());
+        }
+        let width = bucket_width(from, to, resolution);
+
+        let rows = sqlx::query(
+            "SELECT MIN(generation), MAX(generation), COUNT(*),
+                    MIN(best_fitness), MAX(best_fitness), AVG(best_fitness),
+                    MIN(mean_fitness), MAX(mean_fitness), AVG(mean_fitness),
+                    MIN(worst_fitness), MAX(worst_fitness), AVG(worst_fitness),
+                    MIN(diversity), MAX(diversity), AVG(diversity)
+             FROM evolution_history
+             WHERE run_id = ? AND generation BETWEEN ? AND ?
+             GROUP BY (generation - ?) / ?
+             ORDER BY MIN(generation)"
+        )
+        .bind(run_id)
+        .bind(from as i64)
+        .bind(to as i64)
+        .bind(from as i64)
+        .bind(width as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let series = |r: &sqlx::sqlite::SqliteRow, i: usize| SeriesBucket {
+            min: r.get(i),
+            max: r.get(i + 1),
+            mean: r.get(i + 2),
+        };
+        Ok(rows
+            .iter()
+            .map(|r| HistoryBucket {
+                start_generation: r.get::<i64, _>(0) as u64,
+                end_generation: r.get::<i64, _>(1) as u64,
+                count: r.get::<i64, _>(2) as u64,
+                best_fitness: series(r, 3),
+                mean_fitness: series(r, 6),
+                worst_fitness: series(r, 9),
+                diversity: series(r, 12),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::statistics::PopulationStats;
+
+    fn point(generation: u64, best_fitness: f32) -> HistoryPoint {
+        HistoryPoint {
+            generation,
+            best_fitness,
+            species_count: 1,
+            species_sizes: vec![4],
+            mutation: None,
+            stats: PopulationStats { mean: 1.0, ..PopulationStats::default() },
+            rebirth: None,
+            hypervolume: None,
+        }
+    }
+
+    async fn memory_store() -> HistoryStore {
+        // One connection: every in-memory connection is a separate database.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        HistoryStore::new(pool).await
+    }
+
+    #[tokio::test]
+    async fn downsamples_into_min_max_mean_buckets() {
+        let store = memory_store().await;
+        for generation in 1..=10u64 {
+            store.record("world", &point(generation, generation as f32)).await.unwrap();
+        }
+
+        assert_eq!(bucket_width(1, 10, 3), 4);
+        let buckets = store.query("world", 1, 10, 3).await.unwrap();
+        let spans: Vec<_> = buckets.iter().map(|b| (b.start_generation, b.end_generation, b.count)).collect();
+        assert_eq!(spans, vec![(1, 4, 4), (5, 8, 4), (9, 10, 2)]);
+        assert_eq!(buckets[0].best_fitness, SeriesBucket { min: 1.0, max: 4.0, mean: 2.5 });
+        assert_eq!(buckets[2].mean_fitness.mean, 1.0);
+
+        assert_eq!(store.latest_generation("world").await.unwrap(), Some(10));
+        assert_eq!(store.points("world", 3, 4).await.unwrap()[1].generation, 4);
+        assert!(store.query("other", 1, 10, 3).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn runs_of_restarted_processes_are_kept_apart() {
+        let store = memory_store().await;
+        // A restart starts again at generation 0 under a new run ID.
+        for generation in 0..5u64 {
+            store.record("world/100", &point(generation, 1.0)).await.unwrap();
+        }
+        for generation in 0..3u64 {
+            store.record("world/200", &point(generation, 2.0)).await.unwrap();
+        }
+
+        assert_eq!(store.runs().await.unwrap(), vec!["world/100", "world/200"]);
+        let first = store.points("world/100", 0, 10).await.unwrap();
+        assert_eq!(first.len(), 5);
+        assert!(first.iter().all(|p| p.best_fitness == 1.0));
+        let second = store.points("world/200", 0, 10).await.unwrap();
+        assert_eq!(second.len(), 3);
+        assert!(second.iter().all(|p| p.best_fitness == 2.0));
+        assert_eq!(store.latest_generation("world/100").await.unwrap(), Some(4));
+    }
+}
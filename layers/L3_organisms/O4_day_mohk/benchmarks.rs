@@ -0,0 +1,495 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Benchmark problems for the Phoenix Engine.
+//!
+//! Standard optimization problems with known optima, used to check that a
+//! change to the mutator, selection or any other operator really improves
+//! convergence. Every problem decodes `Dna.genes` in `[0, 1]` into its own
+//! domain (real vectors, bit strings or tours) and scores genomes as a
+//! [`FitnessEvaluator`] (minimization problems are negated, so higher is
+//! always better). [`BenchmarkRun`] evolves a problem and records a
+//! [`ConvergenceCurve`] that can be written as CSV.
+
+use std::f64::consts::PI;
+use std::io::{self, Write};
+
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{
+    BlockchainStorage, Dna, EvolutionConfig, FitnessEvaluator, Organism, OrganismId, PhoenixEngine,
+    QuantumMutator,
+};
+
+/// Whether a problem's objective is minimized or maximized.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Goal {
+    Minimize,
+    Maximize,
+}
+
+/// A genome decoded into a problem's domain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum Solution {
+    Real(Vec<f64>),
+    Bits(Vec<bool>),
+    /// Visiting order of the cities.
+    Tour(Vec<usize>),
+}
+
+/// 0/1 knapsack instance with integer weights.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KnapsackInstance {
+    pub weights: Vec<u32>,
+    pub values: Vec<u32>,
+    pub capacity: u32,
+}
+
+impl KnapsackInstance {
+    /// `items` random items (weights and values in `1..=100`) with a
+    /// capacity of half the total weight.
+    pub fn random(items: usize, rng: &mut impl Rng) -> Self {
+        let weights: Vec<u32> = (0..items).map(|_| rng.gen_range(1..=100)).collect();
+        let values = (0..items).map(|_| rng.gen_range(1..=100)).collect();
+        let capacity = weights.iter().sum::<u32>() / 2;
+        Self { weights, values, capacity }
+    }
+
+    /// Best total value (dynamic programming over the capacity).
+    pub fn optimum(&self) -> u64 {
+        let mut best = vec![0u64; self.capacity as usize + 1];
+        for (&w, &v) in self.weights.iter().zip(&self.values) {
+            for c in (w as usize..=self.capacity as usize).rev() {
+                best[c] = best[c].max(best[c - w as usize] + v as u64);
+            }
+        }
+        best[self.capacity as usize]
+    }
+
+    /// Items with a gene of at least 0.5, repaired to fit: the items with
+    /// the lowest value per weight are dropped until the load fits.
+    pub fn decode(&self, genes: &[f32]) -> Vec<bool> {
+        let mut taken: Vec<bool> = (0..self.weights.len())
+            .map(|i| genes.get(i).is_some_and(|&g| g >= 0.5))
+            .collect();
+        let mut load: u64 = self.selected(&taken).map(|i| self.weights[i] as u64).sum();
+        if load > self.capacity as u64 {
+            let mut order: Vec<usize> = self.selected(&taken).collect();
+            order.sort_by(|&a, &b| {
+                let ratio = |i: usize| self.values[i] as f64 / self.weights[i] as f64;
+                ratio(a).total_cmp(&ratio(b))
+            });
+            for i in order {
+                if load <= self.capacity as u64 {
+                    break;
+                }
+                taken[i] = false;
+                load -= self.weights[i] as u64;
+            }
+        }
+        taken
+    }
+
+    pub fn value(&self, taken: &[bool]) -> u64 {
+        self.selected(taken).map(|i| self.values[i] as u64).sum()
+    }
+
+    fn selected<'a>(&self, taken: &'a [bool]) -> impl Iterator<Item = usize> + 'a {
+        taken.iter().enumerate().filter(|(_, &t)| t).map(|(i, _)| i)
+    }
+}
+
+/// Travelling salesman instance (Euclidean, closed tour) with its optimum.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TspInstance {
+    pub cities: Vec<[f64; 2]>,
+    /// Length of the shortest tour.
+    pub optimum: f64,
+}
+
+impl TspInstance {
+    /// `n` cities on the unit circle in shuffled order; the shortest tour
+    /// walks the circle and is `n * 2 sin(pi / n)` long.
+    pub fn circle(n: usize, rng: &mut impl Rng) -> Self {
+        let mut cities: Vec<[f64; 2]> = (0..n)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f64 / n as f64;
+                [angle.cos(), angle.sin()]
+            })
+            .collect();
+        cities.shuffle(rng);
+        let optimum = if n < 2 { 0.0 } else { n as f64 * 2.0 * (PI / n as f64).sin() };
+        Self { cities, optimum }
+    }
+
+    /// Random-key decoding: cities are visited in ascending gene order.
+    pub fn decode(&self, genes: &[f32]) -> Vec<usize> {
+        let mut tour: Vec<usize> = (0..self.cities.len()).collect();
+        tour.sort_by(|&a, &b| {
+            let key = |i: usize| genes.get(i).copied().unwrap_or(0.0);
+            key(a).total_cmp(&key(b)).then(a.cmp(&b))
+        });
+        tour
+    }
+
+    pub fn tour_length(&self, tour: &[usize]) -> f64 {
+        let distance = |a: [f64; 2], b: [f64; 2]| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+        tour.iter()
+            .zip(tour.iter().cycle().skip(1))
+            .map(|(&a, &b)| distance(self.cities[a], self.cities[b]))
+            .sum()
+    }
+}
+
+/// A benchmark problem.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Benchmark {
+    /// `sum x_i^2` on `[-5.12, 5.12]^dims`; minimum 0 at the origin.
+    Sphere { dims: usize },
+    /// Highly multimodal; `[-5.12, 5.12]^dims`, minimum 0 at the origin.
+    Rastrigin { dims: usize },
+    /// Curved valley; `[-2.048, 2.048]^dims`, minimum 0 at `(1, ..., 1)`.
+    Rosenbrock { dims: usize },
+    /// Nearly flat outer region; `[-32.768, 32.768]^dims`, minimum 0 at the origin.
+    Ackley { dims: usize },
+    /// Number of ones in a bit string (gene >= 0.5); maximum `bits`.
+    OneMax { bits: usize },
+    /// 0/1 knapsack (one gene per item), maximizing the packed value.
+    Knapsack(KnapsackInstance),
+    /// TSP with random-key permutation encoding (one gene per city).
+    Tsp(TspInstance),
+}
+
+impl Benchmark {
+    /// The standard suite at `dims` genes per problem. Knapsack and TSP
+    /// instances are generated from `seed`.
+    pub fn suite(dims: usize, seed: u64) -> Vec<Benchmark> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        vec![
+            Benchmark::Sphere { dims },
+            Benchmark::Rastrigin { dims },
+            Benchmark::Rosenbrock { dims },
+            Benchmark::Ackley { dims },
+            Benchmark::OneMax { bits: dims },
+            Benchmark::Knapsack(KnapsackInstance::random(dims, &mut rng)),
+            Benchmark::Tsp(TspInstance::circle(dims, &mut rng)),
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Benchmark::Sphere { .. } => "sphere",
+            Benchmark::Rastrigin { .. } => "rastrigin",
+            Benchmark::Rosenbrock { .. } => "rosenbrock",
+            Benchmark::Ackley { .. } => "ackley",
+            Benchmark::OneMax { .. } => "one_max",
+            Benchmark::Knapsack(_) => "knapsack",
+            Benchmark::Tsp(_) => "tsp",
+        }
+    }
+
+    /// Number of genes a genome needs.
+    pub fn genome_len(&self) -> usize {
+        match self {
+            Benchmark::Sphere { dims }
+            | Benchmark::Rastrigin { dims }
+            | Benchmark::Rosenbrock { dims }
+            | Benchmark::Ackley { dims } => *dims,
+            Benchmark::OneMax { bits } => *bits,
+            Benchmark::Knapsack(k) => k.weights.len(),
+            Benchmark::Tsp(t) => t.cities.len(),
+        }
+    }
+
+    pub fn goal(&self) -> Goal {
+        match self {
+            Benchmark::OneMax { .. } | Benchmark::Knapsack(_) => Goal::Maximize,
+            _ => Goal::Minimize,
+        }
+    }
+
+    /// Best achievable objective value.
+    pub fn optimum(&self) -> f64 {
+        match self {
+            Benchmark::OneMax { bits } => *bits as f64,
+            Benchmark::Knapsack(k) => k.optimum() as f64,
+            Benchmark::Tsp(t) => t.optimum,
+            _ => 0.0,
+        }
+    }
+
+    /// Search interval of each coordinate of the real-valued problems.
+    fn bounds(&self) -> (f64, f64) {
+        match self {
+            Benchmark::Rosenbrock { .. } => (-2.048, 2.048),
+            Benchmark::Ackley { .. } => (-32.768, 32.768),
+            _ => (-5.12, 5.12),
+        }
+    }
+
+    /// Map genes in `[0, 1]` into the problem domain. Missing genes read
+    /// as 0 and extra genes are ignored.
+    pub fn decode(&self, genes: &[f32]) -> Solution {
+        let gene = |i: usize| genes.get(i).copied().unwrap_or(0.0);
+        match self {
+            Benchmark::OneMax { bits } => Solution::Bits((0..*bits).map(|i| gene(i) >= 0.5).collect()),
+            Benchmark::Knapsack(k) => Solution::Bits(k.decode(genes)),
+            Benchmark::Tsp(t) => Solution::Tour(t.decode(genes)),
+            _ => {
+                let (lo, hi) = self.bounds();
+                let x = (0..self.genome_len())
+                    .map(|i| lo + (hi - lo) * gene(i).clamp(0.0, 1.0) as f64)
+                    .collect();
+                Solution::Real(x)
+            }
+        }
+    }
+
+    /// Objective value of a genome in the problem's own terms.
+    pub fn objective(&self, genes: &[f32]) -> f64 {
+        match (self, self.decode(genes)) {
+            (Benchmark::Sphere { .. }, Solution::Real(x)) => x.iter().map(|v| v * v).sum(),
+            (Benchmark::Rastrigin { .. }, Solution::Real(x)) => {
+                10.0 * x.len() as f64 + x.iter().map(|v| v * v - 10.0 * (2.0 * PI * v).cos()).sum::<f64>()
+            }
+            (Benchmark::Rosenbrock { .. }, Solution::Real(x)) => x
+                .windows(2)
+                .map(|w| 100.0 * (w[1] - w[0] * w[0]).powi(2) + (1.0 - w[0]).powi(2))
+                .sum(),
+            (Benchmark::Ackley { .. }, Solution::Real(x)) => {
+                if x.is_empty() {
+                    return 0.0;
+                }
+                let n = x.len() as f64;
+                let square = x.iter().map(|v| v * v).sum::<f64>() / n;
+                let cosine = x.iter().map(|v| (2.0 * PI * v).cos()).sum::<f64>() / n;
+                -20.0 * (-0.2 * square.sqrt()).exp() - cosine.exp() + 20.0 + std::f64::consts::E
+            }
+            (Benchmark::OneMax { .. }, Solution::Bits(bits)) => bits.iter().filter(|&&b| b).count() as f64,
+            (Benchmark::Knapsack(k), Solution::Bits(taken)) => k.value(&taken) as f64,
+            (Benchmark::Tsp(t), Solution::Tour(tour)) => t.tour_length(&tour),
+            _ => unreachable!("decode matches the benchmark"),
+        }
+    }
+
+    /// Distance of a genome's objective from the optimum (0 = solved).
+    pub fn error(&self, genes: &[f32]) -> f64 {
+        (self.objective(genes) - self.optimum()).abs()
+    }
+}
+
+impl FitnessEvaluator for Benchmark {
+    fn evaluate(&self, genes: &[f32]) -> f32 {
+        let objective = self.objective(genes) as f32;
+        match self.goal() {
+            Goal::Minimize => -objective,
+            Goal::Maximize => objective,
+        }
+    }
+}
+
+/// One generation of a [`ConvergenceCurve`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConvergencePoint {
+    pub generation: u64,
+    pub evaluations: u64,
+    /// Objective of the best organism.
+    pub best_objective: f64,
+    /// `|best_objective - optimum|`.
+    pub error: f64,
+    pub mean_fitness: f32,
+    pub diversity: f32,
+}
+
+/// Convergence of one benchmark run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConvergenceCurve {
+    pub benchmark: String,
+    /// Label of the configuration under test (e.g. a mutator setting).
+    pub variant: String,
+    pub optimum: f64,
+    pub points: Vec<ConvergencePoint>,
+}
+
+impl ConvergenceCurve {
+    pub const CSV_HEADER: &'static str =
+        "benchmark,variant,generation,evaluations,best_objective,optimum,error,mean_fitness,diversity";
+
+    /// First generation whose error is at most `tolerance`.
+    pub fn solved_at(&self, tolerance: f64) -> Option<u64> {
+        self.points.iter().find(|p| p.error <= tolerance).map(|p| p.generation)
+    }
+
+    /// Write `curves` as one CSV table (with header), one row per generation.
+    pub fn write_csv(curves: &[ConvergenceCurve], mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "{}", Self::CSV_HEADER)?;
+        for curve in curves {
+            for p in &curve.points {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{},{},{}",
+                    curve.benchmark,
+                    curve.variant,
+                    p.generation,
+                    p.evaluations,
+                    p.best_objective,
+                    curve.optimum,
+                    p.error,
+                    p.mean_fitness,
+                    p.diversity
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Settings of one benchmark run. The initial population and the engine RNG
+/// are both derived from `seed`, so variants compared on the same seed start
+/// from identical populations.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    pub benchmark: Benchmark,
+    pub variant: String,
+    pub population_size: usize,
+    pub generations: u64,
+    pub seed: u64,
+    pub config: EvolutionConfig,
+}
+
+impl BenchmarkRun {
+    pub fn new(benchmark: Benchmark) -> Self {
+        Self {
+            benchmark,
+            variant: "default".to_string(),
+            population_size: 64,
+            generations: 100,
+            seed: 0,
+            config: EvolutionConfig { elitism: 1, ..EvolutionConfig::default() },
+        }
+    }
+
+    /// Evolve the benchmark with `quantum`, recording generation 0 and every
+    /// generation after it. The curve ends early if a generation cannot be
+    /// evolved (e.g. `population_size: 0`).
+    pub fn run<Q, B>(&self, quantum: Q, blockchain: B) -> ConvergenceCurve
+    where
+        Q: QuantumMutator,
+        B: BlockchainStorage,
+    {
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        let population = (0..self.population_size as u64)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(self.benchmark.genome_len(), &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk::benchmarks",
+            quantum,
+            blockchain,
+            self.benchmark.clone(),
+            population,
+        )
+        .with_seed(self.seed)
+        .with_config(self.config.clone());
+
+        let mut curve = ConvergenceCurve {
+            benchmark: self.benchmark.name().to_string(),
+            variant: self.variant.clone(),
+            optimum: self.benchmark.optimum(),
+            points: Vec::with_capacity(self.generations as usize + 1),
+        };
+        loop {
+            curve.points.push(self.point(&engine));
+            if engine.generation >= self.generations {
+                return curve;
+            }
+            let generation = engine.generation;
+            engine.evolve(self.population_size);
+            if engine.generation == generation {
+                return curve;
+            }
+        }
+    }
+
+    fn point<Q, B>(&self, engine: &PhoenixEngine<Q, B>) -> ConvergencePoint {
+        let stats = engine.stats();
+        let best = engine
+            .population
+            .iter()
+            .max_by(|a, b| a.fitness.total_cmp(&b.fitness))
+            .map_or(0.0, |o| self.benchmark.objective(&o.dna.genes));
+        ConvergencePoint {
+            generation: engine.generation,
+            evaluations: engine.evaluations(),
+            best_objective: best,
+            error: (best - self.benchmark.optimum()).abs(),
+            mean_fitness: stats.mean,
+            diversity: stats.diversity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l0_quantum::L0QuantumMutator;
+    use crate::layers::l1_chronos::L1ChronosFileStorage;
+
+    #[test]
+    fn optima_are_reached_by_their_decoded_genomes() {
+        let at = |x: f64, (lo, hi): (f64, f64)| ((x - lo) / (hi - lo)) as f32;
+        for benchmark in Benchmark::suite(6, 1) {
+            let genes: Vec<f32> = match &benchmark {
+                Benchmark::Rosenbrock { dims } => vec![at(1.0, benchmark.bounds()); *dims],
+                Benchmark::OneMax { bits } => vec![1.0; *bits],
+                Benchmark::Knapsack(k) => vec![1.0; k.weights.len()],
+                Benchmark::Tsp(t) => {
+                    // Keys that visit the cities in angular order.
+                    t.cities.iter().map(|c| ((c[1].atan2(c[0]) + PI) / (2.0 * PI)) as f32).collect()
+                }
+                _ => vec![0.5; benchmark.genome_len()],
+            };
+            let error = benchmark.error(&genes);
+            match benchmark {
+                // All items repaired down to a feasible, not optimal, load.
+                Benchmark::Knapsack(ref k) => {
+                    let taken = k.decode(&genes);
+                    let load: u32 = taken.iter().zip(&k.weights).filter(|(t, _)| **t).map(|(_, w)| w).sum();
+                    assert!(load <= k.capacity && k.value(&taken) <= k.optimum());
+                }
+                _ => assert!(error < 1e-3, "{} error {error}", benchmark.name()),
+            }
+        }
+
+        // Knapsack DP against brute force.
+        let knapsack = KnapsackInstance { weights: vec![5, 4, 6, 3], values: vec![10, 40, 30, 50], capacity: 10 };
+        assert_eq!(knapsack.optimum(), 90);
+        assert_eq!(Benchmark::Knapsack(knapsack).objective(&[0.0, 1.0, 0.0, 1.0]), 90.0);
+    }
+
+    #[test]
+    fn runs_record_convergence_as_csv() {
+        let run = BenchmarkRun { generations: 15, seed: 3, ..BenchmarkRun::new(Benchmark::Sphere { dims: 4 }) };
+        let curve = run.run(L0QuantumMutator::default(), L1ChronosFileStorage::new(std::env::temp_dir()));
+        assert_eq!(curve.points.len(), 16);
+        assert_eq!(curve.points[15].evaluations, 16 * 64 - 15);
+        assert!(curve.points[15].error < curve.points[0].error);
+        assert_eq!(
+            curve,
+            run.run(L0QuantumMutator::default(), L1ChronosFileStorage::new(std::env::temp_dir()))
+        );
+
+        let mut csv = Vec::new();
+        ConvergenceCurve::write_csv(&[curve], &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 17);
+        assert!(csv.lines().nth(1).unwrap().starts_with("sphere,default,0,64,"));
+
+        let empty = BenchmarkRun { population_size: 0, ..run };
+        let curve = empty.run(L0QuantumMutator::default(), L1ChronosFileStorage::new(std::env::temp_dir()));
+        assert_eq!(curve.points.len(), 1);
+    }
+}
@@ -0,0 +1,376 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Crossover operators for the Phoenix Engine.
+//!
+//! All operators produce a single child. Genes past the shorter parent's
+//! length are inherited from the longer parent, so no genetic material is
+//! silently dropped when parents differ in length. [`AlignedCrossover`]
+//! goes further and aligns variable-length parents before recombining them.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{Dna, MutationParams};
+
+/// Recombines two parent genomes into one child genome.
+pub trait CrossoverOperator {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna;
+}
+
+/// Classic one-point crossover: prefix from `a`, suffix from `b`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OnePointCrossover;
+
+impl CrossoverOperator for OnePointCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        let len = common_len(a, b);
+        if len < 2 {
+            return UniformCrossover::default().crossover(a, b, rng);
+        }
+
+        let point = rng.gen_range(1..len);
+        let mut genes = Vec::with_capacity(len);
+        genes.extend_from_slice(&a.genes[..point]);
+        genes.extend_from_slice(&b.genes[point..len]);
+        with_tail(genes, a, b)
+    }
+}
+
+/// Two-point crossover: the middle segment comes from `b`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TwoPointCrossover;
+
+impl CrossoverOperator for TwoPointCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        let len = common_len(a, b);
+        if len < 3 {
+            return OnePointCrossover.crossover(a, b, rng);
+        }
+
+        let p1 = rng.gen_range(1..len - 1);
+        let p2 = rng.gen_range(p1 + 1..len);
+        let mut genes = Vec::with_capacity(len);
+        genes.extend_from_slice(&a.genes[..p1]);
+        genes.extend_from_slice(&b.genes[p1..p2]);
+        genes.extend_from_slice(&a.genes[p2..len]);
+        with_tail(genes, a, b)
+    }
+}
+
+/// Uniform crossover: each gene is taken from `b` with `swap_probability`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UniformCrossover {
+    pub swap_probability: f32,
+}
+
+impl Default for UniformCrossover {
+    fn default() -> Self {
+        Self { swap_probability: 0.5 }
+    }
+}
+
+impl CrossoverOperator for UniformCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        let genes = a
+            .genes
+            .iter()
+            .zip(&b.genes)
+            .map(|(&x, &y)| if rng.gen::<f32>() < self.swap_probability { y } else { x })
+            .collect();
+        with_tail(genes, a, b)
+    }
+}
+
+/// Blend crossover (BLX-α) for continuous genes.
+///
+/// Each child gene is drawn uniformly from the parents' interval extended by
+/// `alpha` times its width on both sides, then clamped to \[0, 1\].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BlendCrossover {
+    /// Clamped to `0.0..=MAX_ALPHA`.
+    pub alpha: f32,
+}
+
+impl BlendCrossover {
+    /// Largest extension used; wider intervals only push more genes onto
+    /// the clamp bounds.
+    pub const MAX_ALPHA: f32 = 1.0;
+}
+
+impl Default for BlendCrossover {
+    fn default() -> Self {
+        Self { alpha: 0.5 }
+    }
+}
+
+impl CrossoverOperator for BlendCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        // `clamp` keeps NaN, which would poison the interval below.
+        let alpha = if self.alpha.is_nan() { 0.0 } else { self.alpha.clamp(0.0, Self::MAX_ALPHA) };
+        let genes = a
+            .genes
+            .iter()
+            .zip(&b.genes)
+            .map(|(&x, &y)| {
+                let (lo, hi) = (x.min(y), x.max(y));
+                let spread = (hi - lo) * alpha;
+                let (lo, hi) = (lo - spread, hi + spread);
+                let g = if lo.is_finite() && hi.is_finite() && hi > lo { rng.gen_range(lo..=hi) } else { lo };
+                g.clamp(0.0, 1.0)
+            })
+            .collect();
+        with_tail(genes, a, b)
+    }
+}
+
+/// Simulated binary crossover (SBX) with distribution index `eta`.
+///
+/// Larger `eta` keeps children closer to their parents. Each gene pair is
+/// recombined with probability 0.5 and one of the two SBX children is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedBinaryCrossover {
+    pub eta: f32,
+}
+
+impl Default for SimulatedBinaryCrossover {
+    fn default() -> Self {
+        Self { eta: 15.0 }
+    }
+}
+
+impl CrossoverOperator for SimulatedBinaryCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        let exponent = 1.0 / (self.eta.max(0.0) + 1.0);
+        let genes = a
+            .genes
+            .iter()
+            .zip(&b.genes)
+            .map(|(&x, &y)| {
+                if rng.gen::<f32>() >= 0.5 {
+                    return x;
+                }
+                let u: f32 = rng.gen();
+                let beta = if u <= 0.5 {
+                    (2.0 * u).powf(exponent)
+                } else {
+                    (1.0 / (2.0 * (1.0 - u))).powf(exponent)
+                };
+                let child = if rng.gen::<bool>() {
+                    0.5 * ((1.0 + beta) * x + (1.0 - beta) * y)
+                } else {
+                    0.5 * ((1.0 - beta) * x + (1.0 + beta) * y)
+                };
+                if child.is_finite() { child.clamp(0.0, 1.0) } else { x }
+            })
+            .collect();
+        with_tail(genes, a, b)
+    }
+}
+
+/// Alignment-aware crossover for variable-length genomes.
+///
+/// Parents are globally aligned (Needleman–Wunsch, scoring a pair by minus
+/// its gene difference and a gap by minus `gap_penalty`). Aligned pairs
+/// contribute one gene from either parent; genes aligned to a gap (i.e.
+/// insertions present in only one parent) are inherited with probability
+/// 0.5, so the child's length lies between its parents'.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AlignedCrossover {
+    pub gap_penalty: f32,
+}
+
+impl Default for AlignedCrossover {
+    fn default() -> Self {
+        Self { gap_penalty: 0.3 }
+    }
+}
+
+impl AlignedCrossover {
+    /// Optimal global alignment as `(index in a, index in b)` columns, where
+    /// `None` marks a gap.
+    pub fn align(&self, a: &[f32], b: &[f32]) -> Vec<(Option<usize>, Option<usize>)> {
+        let (n, m) = (a.len(), b.len());
+        let gap = self.gap_penalty.max(0.0);
+        let width = m + 1;
+        let mut score = vec![0.0f32; (n + 1) * width];
+        for i in 1..=n {
+            score[i * width] = -gap * i as f32;
+        }
+        for (j, cell) in score[..width].iter_mut().enumerate() {
+            *cell = -gap * j as f32;
+        }
+        for i in 1..=n {
+            for j in 1..=m {
+                let diag = score[(i - 1) * width + j - 1] - (a[i - 1] - b[j - 1]).abs();
+                let up = score[(i - 1) * width + j] - gap;
+                let left = score[i * width + j - 1] - gap;
+                score[i * width + j] = diag.max(up).max(left);
+            }
+        }
+
+        let mut columns = Vec::with_capacity(n.max(m));
+        let (mut i, mut j) = (n, m);
+        while i > 0 || j > 0 {
+            let here = score[i * width + j];
+            if i > 0 && j > 0 && here == score[(i - 1) * width + j - 1] - (a[i - 1] - b[j - 1]).abs() {
+                columns.push((Some(i - 1), Some(j - 1)));
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && (j == 0 || here == score[(i - 1) * width + j] - gap) {
+                columns.push((Some(i - 1), None));
+                i -= 1;
+            } else {
+                columns.push((None, Some(j - 1)));
+                j -= 1;
+            }
+        }
+        columns.reverse();
+        columns
+    }
+}
+
+impl CrossoverOperator for AlignedCrossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        let genes = self
+            .align(&a.genes, &b.genes)
+            .into_iter()
+            .filter_map(|column| match column {
+                (Some(i), Some(j)) => Some(if rng.gen::<bool>() { b.genes[j] } else { a.genes[i] }),
+                (Some(i), None) => rng.gen::<bool>().then(|| a.genes[i]),
+                (None, Some(j)) => rng.gen::<bool>().then(|| b.genes[j]),
+                (None, None) => None,
+            })
+            .collect();
+        Dna { genes, strategy: recombine_strategy(a, b) }
+    }
+}
+
+/// Serializable choice of crossover operator, used in `EvolutionConfig`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Crossover {
+    #[default]
+    OnePoint,
+    TwoPoint,
+    Uniform(UniformCrossover),
+    Blend(BlendCrossover),
+    SimulatedBinary(SimulatedBinaryCrossover),
+    Aligned(AlignedCrossover),
+}
+
+impl Crossover {
+    /// Whether children of equal-length parents always keep that length.
+    pub fn preserves_length(&self) -> bool {
+        !matches!(self, Crossover::Aligned(_))
+    }
+}
+
+impl CrossoverOperator for Crossover {
+    fn crossover<R: Rng + ?Sized>(&self, a: &Dna, b: &Dna, rng: &mut R) -> Dna {
+        match self {
+            Crossover::OnePoint => OnePointCrossover.crossover(a, b, rng),
+            Crossover::TwoPoint => TwoPointCrossover.crossover(a, b, rng),
+            Crossover::Uniform(op) => op.crossover(a, b, rng),
+            Crossover::Blend(op) => op.crossover(a, b, rng),
+            Crossover::SimulatedBinary(op) => op.crossover(a, b, rng),
+            Crossover::Aligned(op) => op.crossover(a, b, rng),
+        }
+    }
+}
+
+fn common_len(a: &Dna, b: &Dna) -> usize {
+    a.genes.len().min(b.genes.len())
+}
+
+/// Append the genes of the longer parent that lie past `genes.len()`.
+fn with_tail(mut genes: Vec<f32>, a: &Dna, b: &Dna) -> Dna {
+    let longer = if a.genes.len() >= b.genes.len() { a } else { b };
+    if let Some(tail) = longer.genes.get(genes.len()..) {
+        genes.extend_from_slice(tail);
+    }
+    Dna { genes, strategy: recombine_strategy(a, b) }
+}
+
+/// Self-adaptive strategies are recombined intermediately (the mean of both
+/// parents), or inherited from whichever parent carries one.
+fn recombine_strategy(a: &Dna, b: &Dna) -> Option<MutationParams> {
+    match (&a.strategy, &b.strategy) {
+        (Some(x), Some(y)) => Some(MutationParams {
+            mutation_rate: (x.mutation_rate + y.mutation_rate) / 2.0,
+            sigma: (x.sigma + y.sigma) / 2.0,
+        }),
+        (x, y) => x.clone().or_else(|| y.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_operators() -> Vec<Crossover> {
+        vec![
+            Crossover::OnePoint,
+            Crossover::TwoPoint,
+            Crossover::Uniform(UniformCrossover::default()),
+            Crossover::Blend(BlendCrossover::default()),
+            Crossover::SimulatedBinary(SimulatedBinaryCrossover::default()),
+        ]
+    }
+
+    #[test]
+    fn aligned_crossover_matches_shifted_genomes() {
+        let op = AlignedCrossover::default();
+        let a = [0.1, 0.2, 0.9, 0.3];
+        let b = [0.1, 0.2, 0.3];
+        let columns = op.align(&a, &b);
+        assert_eq!(
+            columns,
+            vec![(Some(0), Some(0)), (Some(1), Some(1)), (Some(2), None), (Some(3), Some(2))]
+        );
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..50 {
+            let child = op.crossover(&Dna::from_genes(a.to_vec()), &Dna::from_genes(b.to_vec()), &mut rng);
+            assert!((3..=4).contains(&child.genes.len()));
+        }
+    }
+
+    #[test]
+    fn operators_handle_short_and_uneven_parents() {
+        let mut rng = rand::thread_rng();
+        let one = Dna::from_genes(vec![0.2]);
+        let other = Dna::from_genes(vec![0.9]);
+        let long = Dna::new_random(7, &mut rng);
+
+        for op in all_operators() {
+            let child = op.crossover(&one, &other, &mut rng);
+            assert_eq!(child.genes.len(), 1, "{op:?}");
+
+            let child = op.crossover(&one, &long, &mut rng);
+            assert_eq!(child.genes.len(), 7, "{op:?}");
+            assert_eq!(child.genes[1..], long.genes[1..], "{op:?}");
+
+            let child = op.crossover(&long, &Dna::from_genes(Vec::new()), &mut rng);
+            assert_eq!(child.genes, long.genes, "{op:?}");
+        }
+    }
+
+    #[test]
+    fn blend_operators_stay_in_unit_interval() {
+        let mut rng = rand::thread_rng();
+        for op in all_operators() {
+            for _ in 0..200 {
+                let a = Dna::new_random(6, &mut rng);
+                let b = Dna::new_random(6, &mut rng);
+                let child = op.crossover(&a, &b, &mut rng);
+                assert!(child.genes.iter().all(|g| (0.0..=1.0).contains(g)), "{op:?}");
+            }
+        }
+
+        // Out-of-range alphas are clamped instead of panicking in `gen_range`.
+        let (a, b) = (Dna::from_genes(vec![0.2, 0.4]), Dna::from_genes(vec![0.6, 0.4]));
+        for alpha in [f32::INFINITY, f32::MAX, f32::NAN, -1.0] {
+            let child = BlendCrossover { alpha }.crossover(&a, &b, &mut rng);
+            assert!(child.genes.iter().all(|g| (0.0..=1.0).contains(g)), "{alpha}");
+        }
+    }
+}
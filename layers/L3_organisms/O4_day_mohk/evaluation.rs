@@ -0,0 +1,157 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Parallel fitness evaluation and offspring broods.
+//!
+//! Scoring dominates the cost of a generation once the fitness function
+//! simulates something or the population reaches the tens of thousands.
+//! Large batches are spread over the rayon thread pool. Every organism's
+//! score is written to its own slot, so results never depend on thread
+//! scheduling and a seeded run stays bit-identical in parallel.
+//!
+//! A [`Brood`] is the offspring of one generation between breeding and
+//! committing (see [`PhoenixEngine::breed`](super::phoenix_engine::PhoenixEngine::breed)).
+//! It owns everything needed to score itself, so callers can evaluate it
+//! without holding a lock on the engine.
+
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::genealogy::MutationRecord;
+use super::nsga2::ObjectiveEvaluator;
+use super::phoenix_engine::{FitnessEvaluator, MutationParams, Organism};
+use super::speciation;
+
+/// How populations are scored.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParallelEvaluation {
+    pub enabled: bool,
+    /// Batches smaller than this are scored on the calling thread, where
+    /// the cost of fanning out would outweigh the gain.
+    pub min_batch: usize,
+}
+
+impl Default for ParallelEvaluation {
+    fn default() -> Self {
+        Self { enabled: true, min_batch: 256 }
+    }
+}
+
+impl ParallelEvaluation {
+    /// Score every organism in place.
+    pub fn evaluate(&self, fitness: &dyn FitnessEvaluator, organisms: &mut [Organism]) {
+        if self.enabled && organisms.len() >= self.min_batch.max(2) {
+            organisms
+                .par_iter_mut()
+                .for_each(|o| o.fitness = fitness.evaluate(&o.dna.genes));
+        } else {
+            for o in organisms {
+                o.fitness = fitness.evaluate(&o.dna.genes);
+            }
+        }
+    }
+
+    /// Store every organism's objective vector in place.
+    pub fn evaluate_objectives(&self, objectives: &dyn ObjectiveEvaluator, organisms: &mut [Organism]) {
+        if self.enabled && organisms.len() >= self.min_batch.max(2) {
+            organisms
+                .par_iter_mut()
+                .for_each(|o| o.objectives = objectives.evaluate(&o.dna.genes));
+        } else {
+            for o in organisms {
+                o.objectives = objectives.evaluate(&o.dna.genes);
+            }
+        }
+    }
+}
+
+/// Offspring of one generation, bred but not yet committed to the engine.
+pub struct Brood {
+    /// Engine generation the brood was bred from.
+    pub(crate) generation: u64,
+    /// Engine epoch the brood was bred in.
+    pub(crate) epoch: u64,
+    /// Elites carried over unchanged (empty under crowding).
+    pub(crate) elites: Vec<Organism>,
+    pub(crate) children: Vec<Organism>,
+    /// Mutations that produced each child, in `children` order.
+    pub(crate) mutations: Vec<Vec<MutationRecord>>,
+    /// Fitness of each child's better parent, for the success ratio.
+    pub(crate) parent_fitness: Vec<f32>,
+    pub(crate) mutation: Option<MutationParams>,
+    pub(crate) fitness: Arc<dyn FitnessEvaluator>,
+    /// Set in multi-objective mode; children then get objective vectors
+    /// and are ranked on commit.
+    pub(crate) objectives: Option<Arc<dyn ObjectiveEvaluator>>,
+    pub(crate) parallel: ParallelEvaluation,
+    /// Set when the next history point reports species and the brood will
+    /// replace the population: speciation then runs here, off the engine.
+    pub(crate) species_threshold: Option<f32>,
+    pub(crate) species_sizes: Option<Vec<usize>>,
+    pub(crate) evaluated: bool,
+}
+
+impl Brood {
+    /// Generation the brood was bred from.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Number of children to score.
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn is_evaluated(&self) -> bool {
+        self.evaluated
+    }
+
+    /// Score the children (once). Needs no access to the engine.
+    pub fn evaluate(&mut self) {
+        if !self.evaluated {
+            match &self.objectives {
+                Some(objectives) => self.parallel.evaluate_objectives(&**objectives, &mut self.children),
+                None => self.parallel.evaluate(&*self.fitness, &mut self.children),
+            }
+            if let Some(threshold) = self.species_threshold {
+                let next: Vec<Organism> = self.elites.iter().chain(&self.children).cloned().collect();
+                self.species_sizes =
+                    Some(speciation::speciate(&next, threshold).iter().map(|s| s.members.len()).collect());
+            }
+            self.evaluated = true;
+        }
+    }
+
+    /// [`Brood::evaluate`], by value.
+    pub fn evaluated(mut self) -> Self {
+        self.evaluate();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, OrganismId};
+
+    #[test]
+    fn parallel_scores_match_serial_scores_in_order() {
+        let fitness = |genes: &[f32]| genes.iter().map(|g| (g * 7.3).sin()).sum::<f32>();
+        let organisms: Vec<Organism> = (0..2_000)
+            .map(|i| Organism::new(OrganismId(i), Dna::from_genes(vec![i as f32 * 0.01; 3])))
+            .collect();
+
+        let mut serial = organisms.clone();
+        ParallelEvaluation { enabled: false, ..Default::default() }.evaluate(&fitness, &mut serial);
+        let mut parallel = organisms;
+        ParallelEvaluation { enabled: true, min_batch: 1 }.evaluate(&fitness, &mut parallel);
+
+        let bits = |o: &[Organism]| o.iter().map(|o| (o.id.0, o.fitness.to_bits())).collect::<Vec<_>>();
+        assert_eq!(bits(&serial), bits(&parallel));
+    }
+}
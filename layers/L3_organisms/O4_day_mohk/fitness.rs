@@ -0,0 +1,54 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Built-in fitness evaluators for the Phoenix Engine.
+//!
+//! Every entry point (API, background auto-evolution, examples) picks one of
+//! these instead of re-implementing the scoring inline.
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::FitnessEvaluator;
+
+/// Scaled sum of all genes – the original Day-Mohk world fitness.
+///
+/// Range: `[0, scale * genes_len]`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct GeneSumFitness {
+    pub scale: f32,
+}
+
+impl Default for GeneSumFitness {
+    fn default() -> Self {
+        Self { scale: 2.0 }
+    }
+}
+
+impl FitnessEvaluator for GeneSumFitness {
+    fn evaluate(&self, genes: &[f32]) -> f32 {
+        genes.iter().sum::<f32>() * self.scale
+    }
+}
+
+/// Prefers genes close to `target`, with a linear penalty for distance.
+///
+/// Range: roughly `[0, genes_len]`; each gene contributes `1 - |g - target|`.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct TargetPeakFitness {
+    pub target: f32,
+}
+
+impl Default for TargetPeakFitness {
+    fn default() -> Self {
+        // Peak around 0.8, mild penalty near 0.0 or 1.0.
+        Self { target: 0.8 }
+    }
+}
+
+impl FitnessEvaluator for TargetPeakFitness {
+    fn evaluate(&self, genes: &[f32]) -> f32 {
+        genes
+            .iter()
+            .map(|&g| 1.0 - (g - self.target).abs())
+            .sum::<f32>()
+            .max(0.0)
+    }
+}
@@ -0,0 +1,799 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: User-defined fitness functions in a small sandboxed language.
+//!
+//! A [`FitnessProgram`] is an arithmetic expression over the genome plus
+//! named constant vectors, e.g. `sum(g[i] * w[i]) - abs(g[0] - 0.8)`. It is
+//! parsed, validated against [`DslLimits`] and compiled into a
+//! [`CompiledFitness`] that plugs into the engine like any other
+//! [`FitnessEvaluator`]. The language has no I/O, no assignment, no
+//! recursion and no unbounded loops: the only iteration is an aggregate over
+//! the genes, and every evaluation runs on a fixed step budget.
+//!
+//! Grammar (loosest binding first):
+//!
+//! ```text
+//! expr    = compare
+//! compare = sum (("<" | "<=" | ">" | ">=" | "==" | "!=") sum)?   -- 1 or 0
+//! sum     = product (("+" | "-") product)*
+//! product = unary (("*" | "/" | "%") unary)*
+//! unary   = "-" unary | power
+//! power   = atom ("^" unary)?
+//! atom    = number | name | name "[" expr "]" | name "(" expr ("," expr)* ")" | "(" expr ")"
+//! ```
+//!
+//! Names: `g` (the genes), `n` (gene count), `i` (position inside an
+//! aggregate), `pi`, `e` and the program's vectors. Indexing floors the
+//! index; out-of-range positions read as 0. Functions: `abs`, `sqrt`,
+//! `exp`, `ln`, `sin`, `cos`, `tanh`, `floor`, `min`, `max`, `pow`,
+//! `clamp(x, lo, hi)`, `if(c, a, b)` (lazy, `c != 0`) and the aggregates
+//! `sum`, `mean` and `prod`, which evaluate their argument for `i` in `0..n`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::FitnessEvaluator;
+
+/// Source of a user fitness function.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FitnessProgram {
+    pub expression: String,
+    /// Named constant vectors the expression can index (e.g. weights).
+    #[serde(default)]
+    pub vectors: BTreeMap<String, Vec<f32>>,
+}
+
+/// Size and cost limits a program must respect.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DslLimits {
+    pub max_source_len: usize,
+    /// Nodes of the parsed expression.
+    pub max_nodes: usize,
+    /// Nesting depth of the parsed expression.
+    pub max_depth: usize,
+    /// Aggregates nested inside each other.
+    pub max_aggregate_nesting: usize,
+    pub max_vectors: usize,
+    pub max_vector_len: usize,
+    /// Longest genome the program is validated for.
+    pub max_genes: usize,
+    /// Steps one evaluation may take (the evaluation time limit). Programs
+    /// whose worst case over `max_genes` genes exceeds it are rejected.
+    pub max_steps: u64,
+    /// Fitness of an evaluation that runs out of steps (longer genomes than
+    /// `max_genes`) or produces a non-finite value.
+    pub penalty: f32,
+}
+
+impl Default for DslLimits {
+    fn default() -> Self {
+        Self {
+            max_source_len: 2_048,
+            max_nodes: 256,
+            max_depth: 64,
+            max_aggregate_nesting: 2,
+            max_vectors: 8,
+            max_vector_len: 1_024,
+            max_genes: 256,
+            max_steps: 200_000,
+            penalty: -1.0e6,
+        }
+    }
+}
+
+/// Why a program was rejected, with the byte offset in the expression.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DslError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl DslError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self { position, message: message.into() }
+    }
+}
+
+impl fmt::Display for DslError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for DslError {}
+
+/// Size and worst-case cost of a compiled program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramInfo {
+    pub nodes: usize,
+    pub depth: usize,
+    /// Steps of one evaluation on a genome of `DslLimits::max_genes` genes.
+    pub worst_case_steps: u64,
+}
+
+impl FitnessProgram {
+    pub fn new(expression: impl Into<String>) -> Self {
+        Self { expression: expression.into(), vectors: BTreeMap::new() }
+    }
+
+    /// Add a named constant vector (builder style).
+    pub fn with_vector(mut self, name: impl Into<String>, values: Vec<f32>) -> Self {
+        self.vectors.insert(name.into(), values);
+        self
+    }
+
+    /// Parse, validate and compile the program.
+    pub fn compile(&self, limits: &DslLimits) -> Result<CompiledFitness, DslError> {
+        if self.expression.len() > limits.max_source_len {
+            return Err(DslError::new(
+                limits.max_source_len,
+                format!("expression longer than {} bytes", limits.max_source_len),
+            ));
+        }
+        if self.vectors.len() > limits.max_vectors {
+            return Err(DslError::new(0, format!("more than {} vectors", limits.max_vectors)));
+        }
+        for (name, values) in &self.vectors {
+            if !is_identifier(name)
+                || RESERVED.contains(&name.as_str())
+                || Func::from_name(name).is_some()
+                || Aggregate::from_name(name).is_some()
+            {
+                return Err(DslError::new(0, format!("invalid vector name `{name}`")));
+            }
+            if values.len() > limits.max_vector_len {
+                return Err(DslError::new(0, format!("vector `{name}` longer than {}", limits.max_vector_len)));
+            }
+            if values.iter().any(|v| !v.is_finite()) {
+                return Err(DslError::new(0, format!("vector `{name}` has non-finite values")));
+            }
+        }
+
+        let tokens = tokenize(&self.expression)?;
+        let mut parser = Parser { tokens: &tokens, pos: 0, depth: 0, limits, end: self.expression.len() };
+        let ast = parser.expr()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            return Err(DslError::new(token.position, format!("unexpected {}", token.kind)));
+        }
+
+        let names: Vec<&String> = self.vectors.keys().collect();
+        let mut compiler = Compiler { names: &names, nodes: 0, limits };
+        let root = compiler.compile(&ast, 0)?;
+        if compiler.nodes > limits.max_nodes {
+            return Err(DslError::new(0, format!("more than {} nodes", limits.max_nodes)));
+        }
+        let worst_case_steps = root.cost(limits.max_genes as u64);
+        if worst_case_steps > limits.max_steps {
+            return Err(DslError::new(
+                0,
+                format!(
+                    "worst case of {worst_case_steps} steps on {} genes exceeds {}",
+                    limits.max_genes, limits.max_steps
+                ),
+            ));
+        }
+
+        Ok(CompiledFitness {
+            info: ProgramInfo { nodes: compiler.nodes, depth: root.depth(), worst_case_steps },
+            root,
+            vectors: self.vectors.values().cloned().collect(),
+            max_steps: limits.max_steps,
+            penalty: limits.penalty,
+        })
+    }
+}
+
+/// A validated program, ready to score genomes.
+#[derive(Clone, Debug)]
+pub struct CompiledFitness {
+    info: ProgramInfo,
+    root: Node,
+    vectors: Vec<Vec<f32>>,
+    max_steps: u64,
+    penalty: f32,
+}
+
+impl CompiledFitness {
+    pub fn info(&self) -> ProgramInfo {
+        self.info
+    }
+}
+
+impl FitnessEvaluator for CompiledFitness {
+    fn evaluate(&self, genes: &[f32]) -> f32 {
+        let mut eval = Eval { genes, vectors: &self.vectors, positions: Vec::new(), steps_left: self.max_steps };
+        match eval.run(&self.root) {
+            Some(value) if value.is_finite() => value as f32,
+            _ => self.penalty,
+        }
+    }
+}
+
+// --- Lexer -----------------------------------------------------------------
+
+const RESERVED: [&str; 5] = ["g", "n", "i", "pi", "e"];
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenKind {
+    Number(f64),
+    Name(String),
+    Op(&'static str),
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Number(x) => write!(f, "number {x}"),
+            TokenKind::Name(name) => write!(f, "`{name}`"),
+            TokenKind::Op(op) => write!(f, "`{op}`"),
+        }
+    }
+}
+
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+const OPERATORS: [&str; 17] =
+    ["<=", ">=", "==", "!=", "<", ">", "+", "-", "*", "/", "%", "^", "(", ")", "[", "]", ","];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, DslError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        if c.is_ascii_whitespace() {
+            pos += 1;
+        } else if c.is_ascii_digit() || c == b'.' {
+            let start = pos;
+            while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'.') {
+                pos += 1;
+            }
+            if pos < bytes.len() && (bytes[pos] == b'e' || bytes[pos] == b'E') {
+                let mut end = pos + 1;
+                if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+                    end += 1;
+                }
+                if end < bytes.len() && bytes[end].is_ascii_digit() {
+                    pos = end;
+                    while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                        pos += 1;
+                    }
+                }
+            }
+            let text = &source[start..pos];
+            let value = text
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| DslError::new(start, format!("invalid number `{text}`")))?;
+            tokens.push(Token { kind: TokenKind::Number(value), position: start });
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            let start = pos;
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            tokens.push(Token { kind: TokenKind::Name(source[start..pos].to_string()), position: start });
+        } else {
+            let op = OPERATORS.iter().find(|op| source[pos..].starts_with(*op)).ok_or_else(|| {
+                DslError::new(
+                    pos,
+                    format!("unexpected character `{}`", source[pos..].chars().next().unwrap_or('?')),
+                )
+            })?;
+            tokens.push(Token { kind: TokenKind::Op(op), position: pos });
+            pos += op.len();
+        }
+    }
+    Ok(tokens)
+}
+
+// --- Parser ----------------------------------------------------------------
+
+#[derive(Debug)]
+enum Ast {
+    Number(f64),
+    Name(String, usize),
+    Index(String, Box<Ast>, usize),
+    Call(String, Vec<Ast>, usize),
+    Neg(Box<Ast>),
+    Binary(BinaryOp, Box<Ast>, Box<Ast>),
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    depth: usize,
+    limits: &'a DslLimits,
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token { kind: TokenKind::Op(op), .. }) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |t| t.position)
+    }
+
+    fn expect(&mut self, op: &'static str) -> Result<(), DslError> {
+        if self.peek_op() == Some(op) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(DslError::new(self.position(), format!("expected `{op}`")))
+        }
+    }
+
+    /// Guards the recursion depth, so hostile input cannot overflow the stack.
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, DslError>) -> Result<T, DslError> {
+        self.depth += 1;
+        if self.depth > self.limits.max_depth {
+            return Err(DslError::new(self.position(), format!("nested deeper than {}", self.limits.max_depth)));
+        }
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn expr(&mut self) -> Result<Ast, DslError> {
+        self.nested(|p| {
+            let left = p.sum()?;
+            let op = match p.peek_op() {
+                Some("<") => BinaryOp::Less,
+                Some("<=") => BinaryOp::LessEqual,
+                Some(">") => BinaryOp::Greater,
+                Some(">=") => BinaryOp::GreaterEqual,
+                Some("==") => BinaryOp::Equal,
+                Some("!=") => BinaryOp::NotEqual,
+                _ => return Ok(left),
+            };
+            p.pos += 1;
+            let right = p.sum()?;
+            Ok(Ast::Binary(op, Box::new(left), Box::new(right)))
+        })
+    }
+
+    fn sum(&mut self) -> Result<Ast, DslError> {
+        let mut left = self.product()?;
+        loop {
+            let op = match self.peek_op() {
+                Some("+") => BinaryOp::Add,
+                Some("-") => BinaryOp::Sub,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            left = Ast::Binary(op, Box::new(left), Box::new(self.product()?));
+        }
+    }
+
+    fn product(&mut self) -> Result<Ast, DslError> {
+        let mut left = self.unary()?;
+        loop {
+            let op = match self.peek_op() {
+                Some("*") => BinaryOp::Mul,
+                Some("/") => BinaryOp::Div,
+                Some("%") => BinaryOp::Rem,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            left = Ast::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Ast, DslError> {
+        self.nested(|p| {
+            if p.peek_op() == Some("-") {
+                p.pos += 1;
+                return Ok(Ast::Neg(Box::new(p.unary()?)));
+            }
+            let base = p.atom()?;
+            if p.peek_op() == Some("^") {
+                p.pos += 1;
+                return Ok(Ast::Binary(BinaryOp::Pow, Box::new(base), Box::new(p.unary()?)));
+            }
+            Ok(base)
+        })
+    }
+
+    fn atom(&mut self) -> Result<Ast, DslError> {
+        let position = self.position();
+        let Some(token) = self.tokens.get(self.pos) else {
+            return Err(DslError::new(position, "unexpected end of expression"));
+        };
+        self.pos += 1;
+        match &token.kind {
+            TokenKind::Number(value) => Ok(Ast::Number(*value)),
+            TokenKind::Op("(") => {
+                let inner = self.expr()?;
+                self.expect(")")?;
+                Ok(inner)
+            }
+            TokenKind::Name(name) => match self.peek_op() {
+                Some("[") => {
+                    self.pos += 1;
+                    let index = self.expr()?;
+                    self.expect("]")?;
+                    Ok(Ast::Index(name.clone(), Box::new(index), position))
+                }
+                Some("(") => {
+                    self.pos += 1;
+                    let mut args = vec![self.expr()?];
+                    while self.peek_op() == Some(",") {
+                        self.pos += 1;
+                        args.push(self.expr()?);
+                    }
+                    self.expect(")")?;
+                    Ok(Ast::Call(name.clone(), args, position))
+                }
+                _ => Ok(Ast::Name(name.clone(), position)),
+            },
+            TokenKind::Op(op) => Err(DslError::new(position, format!("unexpected `{op}`"))),
+        }
+    }
+}
+
+// --- Compiler --------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+    NotEqual,
+}
+
+impl BinaryOp {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        let flag = |c: bool| if c { 1.0 } else { 0.0 };
+        match self {
+            BinaryOp::Add => a + b,
+            BinaryOp::Sub => a - b,
+            BinaryOp::Mul => a * b,
+            BinaryOp::Div => a / b,
+            BinaryOp::Rem => a % b,
+            BinaryOp::Pow => a.powf(b),
+            BinaryOp::Less => flag(a < b),
+            BinaryOp::LessEqual => flag(a <= b),
+            BinaryOp::Greater => flag(a > b),
+            BinaryOp::GreaterEqual => flag(a >= b),
+            BinaryOp::Equal => flag(a == b),
+            BinaryOp::NotEqual => flag(a != b),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Func {
+    Abs,
+    Sqrt,
+    Exp,
+    Ln,
+    Sin,
+    Cos,
+    Tanh,
+    Floor,
+    Min,
+    Max,
+    Pow,
+    Clamp,
+    If,
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "abs" => Func::Abs,
+            "sqrt" => Func::Sqrt,
+            "exp" => Func::Exp,
+            "ln" => Func::Ln,
+            "sin" => Func::Sin,
+            "cos" => Func::Cos,
+            "tanh" => Func::Tanh,
+            "floor" => Func::Floor,
+            "min" => Func::Min,
+            "max" => Func::Max,
+            "pow" => Func::Pow,
+            "clamp" => Func::Clamp,
+            "if" => Func::If,
+            _ => return None,
+        })
+    }
+
+    fn arity(self) -> usize {
+        match self {
+            Func::Min | Func::Max | Func::Pow => 2,
+            Func::Clamp | Func::If => 3,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Aggregate {
+    Sum,
+    Mean,
+    Prod,
+}
+
+impl Aggregate {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sum" => Some(Aggregate::Sum),
+            "mean" => Some(Aggregate::Mean),
+            "prod" => Some(Aggregate::Prod),
+            _ => None,
+        }
+    }
+}
+
+/// Compiled expression: names resolved, arities checked, constants folded.
+#[derive(Clone, Debug)]
+enum Node {
+    Const(f64),
+    GeneCount,
+    Position,
+    Gene(Box<Node>),
+    Vector(usize, Box<Node>),
+    Neg(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    Call(Func, Vec<Node>),
+    Aggregate(Aggregate, Box<Node>),
+}
+
+impl Node {
+    /// Steps of one evaluation on `genes` genes (every node costs one).
+    fn cost(&self, genes: u64) -> u64 {
+        match self {
+            Node::Const(_) | Node::GeneCount | Node::Position => 1,
+            Node::Gene(x) | Node::Vector(_, x) | Node::Neg(x) => 1 + x.cost(genes),
+            Node::Binary(_, a, b) => 1 + a.cost(genes).saturating_add(b.cost(genes)),
+            Node::Call(_, args) => args.iter().fold(1u64, |total, a| total.saturating_add(a.cost(genes))),
+            Node::Aggregate(_, body) => 1u64.saturating_add(genes.saturating_mul(body.cost(genes))),
+        }
+    }
+
+    fn depth(&self) -> usize {
+        1 + match self {
+            Node::Const(_) | Node::GeneCount | Node::Position => 0,
+            Node::Gene(x) | Node::Vector(_, x) | Node::Neg(x) | Node::Aggregate(_, x) => x.depth(),
+            Node::Binary(_, a, b) => a.depth().max(b.depth()),
+            Node::Call(_, args) => args.iter().map(Node::depth).max().unwrap_or(0),
+        }
+    }
+}
+
+struct Compiler<'a> {
+    names: &'a [&'a String],
+    nodes: usize,
+    limits: &'a DslLimits,
+}
+
+impl Compiler<'_> {
+    /// `aggregates` = number of enclosing aggregates.
+    fn compile(&mut self, ast: &Ast, aggregates: usize) -> Result<Node, DslError> {
+        self.nodes += 1;
+        let node = match ast {
+            Ast::Number(value) => Node::Const(*value),
+            Ast::Name(name, position) => match name.as_str() {
+                "n" => Node::GeneCount,
+                "pi" => Node::Const(std::f64::consts::PI),
+                "e" => Node::Const(std::f64::consts::E),
+                "i" if aggregates > 0 => Node::Position,
+                "i" => return Err(DslError::new(*position, "`i` is only defined inside sum, mean or prod")),
+                _ => {
+                    return Err(DslError::new(
+                        *position,
+                        format!("unknown name `{name}` (index vectors with `[...]`)"),
+                    ))
+                }
+            },
+            Ast::Index(name, index, position) => {
+                let index = Box::new(self.compile(index, aggregates)?);
+                if name == "g" {
+                    Node::Gene(index)
+                } else if let Some(slot) = self.names.iter().position(|v| *v == name) {
+                    Node::Vector(slot, index)
+                } else {
+                    return Err(DslError::new(*position, format!("unknown vector `{name}`")));
+                }
+            }
+            Ast::Call(name, args, position) => {
+                if let Some(aggregate) = Aggregate::from_name(name) {
+                    if args.len() != 1 {
+                        return Err(DslError::new(*position, format!("`{name}` takes 1 argument")));
+                    }
+                    if aggregates + 1 > self.limits.max_aggregate_nesting {
+                        return Err(DslError::new(
+                            *position,
+                            format!("aggregates nested deeper than {}", self.limits.max_aggregate_nesting),
+                        ));
+                    }
+                    Node::Aggregate(aggregate, Box::new(self.compile(&args[0], aggregates + 1)?))
+                } else if let Some(func) = Func::from_name(name) {
+                    if args.len() != func.arity() {
+                        return Err(DslError::new(
+                            *position,
+                            format!("`{name}` takes {} argument(s)", func.arity()),
+                        ));
+                    }
+                    let args = args.iter().map(|a| self.compile(a, aggregates)).collect::<Result<Vec<_>, _>>()?;
+                    Node::Call(func, args)
+                } else {
+                    return Err(DslError::new(*position, format!("unknown function `{name}`")));
+                }
+            }
+            Ast::Neg(x) => match self.compile(x, aggregates)? {
+                Node::Const(value) => Node::Const(-value),
+                x => Node::Neg(Box::new(x)),
+            },
+            Ast::Binary(op, a, b) => match (self.compile(a, aggregates)?, self.compile(b, aggregates)?) {
+                (Node::Const(a), Node::Const(b)) => Node::Const(op.apply(a, b)),
+                (a, b) => Node::Binary(*op, Box::new(a), Box::new(b)),
+            },
+        };
+        Ok(node)
+    }
+}
+
+// --- Evaluation ------------------------------------------------------------
+
+struct Eval<'a> {
+    genes: &'a [f32],
+    vectors: &'a [Vec<f32>],
+    /// `i` of each enclosing aggregate, innermost last.
+    positions: Vec<usize>,
+    steps_left: u64,
+}
+
+fn read(values: &[f32], index: f64) -> f64 {
+    let index = index.floor();
+    if index >= 0.0 && index < values.len() as f64 {
+        values[index as usize] as f64
+    } else {
+        0.0
+    }
+}
+
+impl Eval<'_> {
+    /// `None` once the step budget is spent.
+    fn run(&mut self, node: &Node) -> Option<f64> {
+        self.steps_left = self.steps_left.checked_sub(1)?;
+        Some(match node {
+            Node::Const(value) => *value,
+            Node::GeneCount => self.genes.len() as f64,
+            Node::Position => *self.positions.last()? as f64,
+            Node::Gene(index) => read(self.genes, self.run(index)?),
+            Node::Vector(slot, index) => read(&self.vectors[*slot], self.run(index)?),
+            Node::Neg(x) => -self.run(x)?,
+            Node::Binary(op, a, b) => op.apply(self.run(a)?, self.run(b)?),
+            Node::Call(Func::If, args) => {
+                if self.run(&args[0])? != 0.0 {
+                    self.run(&args[1])?
+                } else {
+                    self.run(&args[2])?
+                }
+            }
+            Node::Call(func, args) => {
+                let x = self.run(&args[0])?;
+                match func {
+                    Func::Abs => x.abs(),
+                    Func::Sqrt => x.sqrt(),
+                    Func::Exp => x.exp(),
+                    Func::Ln => x.ln(),
+                    Func::Sin => x.sin(),
+                    Func::Cos => x.cos(),
+                    Func::Tanh => x.tanh(),
+                    Func::Floor => x.floor(),
+                    Func::Min => x.min(self.run(&args[1])?),
+                    Func::Max => x.max(self.run(&args[1])?),
+                    Func::Pow => x.powf(self.run(&args[1])?),
+                    Func::Clamp => {
+                        let (lo, hi) = (self.run(&args[1])?, self.run(&args[2])?);
+                        x.max(lo).min(hi)
+                    }
+                    Func::If => unreachable!("handled above"),
+                }
+            }
+            Node::Aggregate(aggregate, body) => {
+                let n = self.genes.len();
+                let mut total = if *aggregate == Aggregate::Prod { 1.0 } else { 0.0 };
+                for i in 0..n {
+                    self.positions.push(i);
+                    let value = self.run(body);
+                    self.positions.pop();
+                    match aggregate {
+                        Aggregate::Prod => total *= value?,
+                        _ => total += value?,
+                    }
+                }
+                if *aggregate == Aggregate::Mean && n > 0 {
+                    total / n as f64
+                } else {
+                    total
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(program: &FitnessProgram, genes: &[f32]) -> f32 {
+        program.compile(&DslLimits::default()).unwrap().evaluate(genes)
+    }
+
+    #[test]
+    fn compiles_and_evaluates_expressions() {
+        let program =
+            FitnessProgram::new("sum(g[i] * w[i]) - abs(g[0] - 0.8)").with_vector("w", vec![1.0, 2.0, 3.0]);
+        let expected = 0.5 + 2.0 * 0.25 + 3.0 * 1.0 - 0.3;
+        assert!((score(&program, &[0.5, 0.25, 1.0]) - expected).abs() < 1e-6);
+
+        let cases = [
+            ("2 ^ 3 ^ 2", 512.0),
+            ("-2 ^ 2", -4.0),
+            ("mean(g[i]) + n", 1.75 / 3.0 + 3.0),
+            ("if(g[0] > 0.4, 10, 20) + clamp(g[9], 1, 2)", 11.0),
+            ("sum(sum(g[i]))", 5.25),
+            ("prod(g[i] + 1) - 1e1 % 4", 1.5 * 1.25 * 2.0 - 2.0),
+            ("max(min(pi, e), 1) == e", 1.0),
+        ];
+        for (expression, expected) in cases {
+            let value = score(&FitnessProgram::new(expression), &[0.5, 0.25, 1.0]);
+            assert!((value - expected as f32).abs() < 1e-5, "{expression} = {value}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_and_oversized_programs() {
+        let limits = DslLimits::default();
+        let error = |expression: &str| FitnessProgram::new(expression).compile(&limits).unwrap_err();
+
+        assert_eq!(error("g[0] +").position, 6);
+        assert_eq!(error("g[0] $ 1").position, 5);
+        assert!(error("w[0]").message.contains("unknown vector"));
+        assert!(error("i + 1").message.contains("only defined inside"));
+        assert!(error("system(1)").message.contains("unknown function"));
+        assert!(error("min(1)").message.contains("2 argument"));
+        assert!(error(&"(".repeat(10_000)).message.contains("longer than"));
+        assert!(error(&format!("{}1{}", "(".repeat(40), ")".repeat(40))).message.contains("deeper"));
+        assert!(error("sum(sum(sum(g[i])))").message.contains("aggregates nested"));
+        assert!(error("sum(sum(g[i] * g[i]))").message.contains("worst case"));
+        assert!(FitnessProgram::new("1").with_vector("sum", vec![1.0]).compile(&limits).is_err());
+    }
+
+    #[test]
+    fn evaluations_beyond_the_step_budget_score_the_penalty() {
+        let limits = DslLimits { max_genes: 8, max_steps: 100, ..DslLimits::default() };
+        let compiled = FitnessProgram::new("sum(g[i])").compile(&limits).unwrap();
+        assert_eq!(compiled.info().worst_case_steps, 1 + 8 * 2);
+        assert_eq!(compiled.evaluate(&[1.0; 8]), 8.0);
+        assert_eq!(compiled.evaluate(&[1.0; 64]), limits.penalty);
+        assert_eq!(FitnessProgram::new("ln(0)").compile(&limits).unwrap().evaluate(&[]), limits.penalty);
+    }
+}
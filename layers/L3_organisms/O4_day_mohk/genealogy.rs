@@ -0,0 +1,265 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Lineage tracking for the Phoenix Engine.
+//!
+//! Every organism born in the engine is recorded with its parents, birth
+//! generation and the mutations it received. The store answers ancestry
+//! queries in both directions and exports family trees as JSON (for the
+//! frontend) or Graphviz DOT.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{Organism, OrganismId};
+
+/// One genetic operation applied while producing a child.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MutationRecord {
+    /// The child is a recombination of both parents.
+    Crossover,
+    /// L0 point mutation changed `genes` gene values.
+    Point { genes: usize },
+    /// A random gene was inserted at `at`.
+    Insertion { at: usize },
+    /// The gene at `at` was removed.
+    Deletion { at: usize },
+    /// Genes `start..start + len` were copied in place after themselves.
+    Duplication { start: usize, len: usize },
+}
+
+/// What the store knows about one organism.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LineageRecord {
+    pub id: OrganismId,
+    pub parents: Vec<OrganismId>,
+    pub birth_generation: u64,
+    /// Fitness at birth.
+    pub fitness: f32,
+    pub mutations: Vec<MutationRecord>,
+}
+
+/// A subgraph of the genealogy, ready for JSON serialization.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FamilyTree {
+    pub root: OrganismId,
+    pub nodes: Vec<LineageRecord>,
+    /// `(parent, child)` edges between nodes of the tree.
+    pub edges: Vec<(OrganismId, OrganismId)>,
+}
+
+/// Bounded store of lineage records.
+///
+/// When more than `capacity` organisms have been recorded the oldest
+/// records are forgotten; queries simply stop at forgotten organisms.
+#[derive(Clone, Debug)]
+pub struct Genealogy {
+    pub capacity: usize,
+    records: HashMap<OrganismId, LineageRecord>,
+    children: HashMap<OrganismId, Vec<OrganismId>>,
+    order: VecDeque<OrganismId>,
+}
+
+impl Default for Genealogy {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl Genealogy {
+    pub const DEFAULT_CAPACITY: usize = 20_000;
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: HashMap::new(),
+            children: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn get(&self, id: &OrganismId) -> Option<&LineageRecord> {
+        self.records.get(id)
+    }
+
+    /// Record the birth of `organism`. Organisms already known are ignored.
+    /// Only parents still in the store get a child link, so organisms with
+    /// unknown parents (migrants, champions, immigrants) leave nothing behind.
+    pub fn record(&mut self, organism: &Organism, mutations: Vec<MutationRecord>) {
+        if self.records.contains_key(&organism.id) {
+            return;
+        }
+        for parent in &organism.parents {
+            if self.records.contains_key(parent) {
+                self.children.entry(parent.clone()).or_default().push(organism.id.clone());
+            }
+        }
+        self.records.insert(
+            organism.id.clone(),
+            LineageRecord {
+                id: organism.id.clone(),
+                parents: organism.parents.clone(),
+                birth_generation: organism.birth_generation,
+                fitness: organism.fitness,
+                mutations,
+            },
+        );
+        self.order.push_back(organism.id.clone());
+
+        while self.records.len() > self.capacity {
+            let Some(oldest) = self.order.pop_front() else { break };
+            self.children.remove(&oldest);
+            let Some(record) = self.records.remove(&oldest) else { continue };
+            for parent in &record.parents {
+                if let Some(siblings) = self.children.get_mut(parent) {
+                    siblings.retain(|child| *child != oldest);
+                    if siblings.is_empty() {
+                        self.children.remove(parent);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Known ancestors of `id` up to `depth` generations back (nearest first).
+    pub fn ancestors(&self, id: &OrganismId, depth: usize) -> Vec<&LineageRecord> {
+        self.walk(id, depth, |record| record.parents.clone())
+    }
+
+    /// Known descendants of `id` up to `depth` generations down (nearest first).
+    pub fn descendants(&self, id: &OrganismId, depth: usize) -> Vec<&LineageRecord> {
+        self.walk(id, depth, |record| self.children.get(&record.id).cloned().unwrap_or_default())
+    }
+
+    /// Breadth-first walk from `id` (exclusive) along `next`.
+    fn walk<F>(&self, id: &OrganismId, depth: usize, next: F) -> Vec<&LineageRecord>
+    where
+        F: Fn(&LineageRecord) -> Vec<OrganismId>,
+    {
+        let mut seen = HashSet::from([id.clone()]);
+        let mut queue = VecDeque::from([(id.clone(), 0)]);
+        let mut found = Vec::new();
+
+        while let Some((current, level)) = queue.pop_front() {
+            let Some(record) = self.records.get(&current) else { continue };
+            if current != *id {
+                found.push(record);
+            }
+            if level == depth {
+                continue;
+            }
+            for neighbour in next(record) {
+                if seen.insert(neighbour.clone()) {
+                    queue.push_back((neighbour, level + 1));
+                }
+            }
+        }
+        found
+    }
+
+    /// Ancestors and descendants of `id` within `depth` generations, plus
+    /// the parent→child edges between them. `None` if `id` is unknown.
+    pub fn family_tree(&self, id: &OrganismId, depth: usize) -> Option<FamilyTree> {
+        let root = self.records.get(id)?;
+        let mut nodes = vec![root.clone()];
+        nodes.extend(self.ancestors(id, depth).into_iter().cloned());
+        nodes.extend(self.descendants(id, depth).into_iter().cloned());
+
+        let members: HashSet<&OrganismId> = nodes.iter().map(|n| &n.id).collect();
+        let mut edges: Vec<_> = nodes
+            .iter()
+            .flat_map(|child| {
+                child
+                    .parents
+                    .iter()
+                    .filter(|parent| members.contains(parent))
+                    .map(|parent| (parent.clone(), child.id.clone()))
+            })
+            .collect();
+        // A child bred from the same organism twice gets a single edge.
+        edges.dedup();
+
+        Some(FamilyTree { root: id.clone(), nodes, edges })
+    }
+}
+
+impl FamilyTree {
+    /// Render the tree as a Graphviz DOT digraph (parents point to children).
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph lineage {\n  rankdir=TB;\n  node [shape=box];\n");
+        for node in &self.nodes {
+            let style = if node.id == self.root { ", style=bold" } else { "" };
+            let _ = writeln!(
+                dot,
+                "  \"{}\" [label=\"{}\\ngen {} | fitness {:.3}\"{}];",
+                node.id.0, node.id.0, node.birth_generation, node.fitness, style
+            );
+        }
+        for (parent, child) in &self.edges {
+            let _ = writeln!(dot, "  \"{}\" -> \"{}\";", parent.0, child.0);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::Dna;
+
+    fn born(id: u64, parents: &[u64], generation: u64) -> Organism {
+        Organism {
+            parents: parents.iter().map(|&p| OrganismId(p)).collect(),
+            birth_generation: generation,
+            ..Organism::new(OrganismId(id), Dna::from_genes(vec![0.5]))
+        }
+    }
+
+    #[test]
+    fn answers_ancestry_queries_and_exports() {
+        let mut genealogy = Genealogy::new(100);
+        for organism in [
+            born(1, &[], 0),
+            born(2, &[], 0),
+            born(3, &[1, 2], 1),
+            born(4, &[3, 3], 2),
+            born(5, &[4, 2], 3),
+        ] {
+            genealogy.record(&organism, vec![MutationRecord::Crossover]);
+        }
+
+        let ancestors: Vec<u64> = genealogy.ancestors(&OrganismId(5), 10).iter().map(|r| r.id.0).collect();
+        assert_eq!(ancestors, vec![4, 2, 3, 1]);
+        let descendants: Vec<u64> = genealogy.descendants(&OrganismId(1), 1).iter().map(|r| r.id.0).collect();
+        assert_eq!(descendants, vec![3]);
+
+        let tree = genealogy.family_tree(&OrganismId(3), 1).unwrap();
+        assert_eq!(tree.nodes.len(), 4);
+        assert!(tree.to_dot().contains("\"3\" -> \"4\";"));
+
+        let mut small = Genealogy::new(2);
+        small.record(&born(1, &[], 0), Vec::new());
+        small.record(&born(2, &[1], 1), Vec::new());
+        small.record(&born(3, &[2], 2), Vec::new());
+        assert!(small.get(&OrganismId(1)).is_none());
+        assert_eq!(small.ancestors(&OrganismId(3), 5).len(), 1);
+
+        // Unknown and evicted parents keep no child lists around.
+        for id in 4..50 {
+            small.record(&born(id, &[id - 1, 1_000 + id], id), Vec::new());
+        }
+        assert_eq!(small.len(), 2);
+        assert_eq!(small.children.keys().collect::<Vec<_>>(), vec![&OrganismId(48)]);
+        assert_eq!(small.descendants(&OrganismId(48), 1).len(), 1);
+    }
+}
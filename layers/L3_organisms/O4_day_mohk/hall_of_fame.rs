@@ -0,0 +1,119 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Hall of fame for the Phoenix Engine.
+//!
+//! `evolve` replaces the population every generation, so the best genomes
+//! of a run can be lost. The hall of fame keeps the top-N distinct
+//! organisms ever seen, together with the generation they were found in.
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{Organism, OrganismId};
+
+/// A champion and the generation in which it entered the hall.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HallOfFameEntry {
+    pub organism: Organism,
+    pub generation: u64,
+}
+
+/// Bounded, best-first set of distinct champions.
+///
+/// Organisms are distinct by genome: a genotype already in the hall is not
+/// added again, even under a different id.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HallOfFame {
+    pub capacity: usize,
+    entries: Vec<HallOfFameEntry>,
+}
+
+impl Default for HallOfFame {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl HallOfFame {
+    pub const DEFAULT_CAPACITY: usize = 20;
+
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Vec::new() }
+    }
+
+    /// Champions, best first.
+    pub fn entries(&self) -> &[HallOfFameEntry] {
+        &self.entries
+    }
+
+    pub fn best(&self) -> Option<&HallOfFameEntry> {
+        self.entries.first()
+    }
+
+    pub fn get(&self, id: &OrganismId) -> Option<&HallOfFameEntry> {
+        self.entries.iter().find(|e| e.organism.id == *id)
+    }
+
+    /// Offer every organism of `population` (found in `generation`).
+    /// Returns how many entered the hall.
+    pub fn update(&mut self, population: &[Organism], generation: u64) -> usize {
+        let mut admitted = 0;
+        for organism in population {
+            // Reject on fitness first: most organisms never beat a full
+            // hall, and the genome comparison is the expensive check.
+            let full = self.entries.len() >= self.capacity;
+            let beats_worst = self.entries.last().is_some_and(|worst| organism.fitness > worst.organism.fitness);
+            if !organism.fitness.is_finite() || (full && !beats_worst) || self.contains_genome(organism) {
+                continue;
+            }
+            if full {
+                self.entries.pop();
+            }
+
+            let at = self.entries.partition_point(|e| e.organism.fitness >= organism.fitness);
+            self.entries.insert(at, HallOfFameEntry { organism: organism.clone(), generation });
+            admitted += 1;
+        }
+        admitted
+    }
+
+    /// Re-score every champion (e.g. after the fitness function changed).
+    pub fn rescore(&mut self, fitness: impl Fn(&[f32]) -> f32) {
+        for entry in &mut self.entries {
+            entry.organism.fitness = fitness(&entry.organism.dna.genes);
+        }
+        self.entries.sort_by(|a, b| b.organism.fitness.total_cmp(&a.organism.fitness));
+    }
+
+    fn contains_genome(&self, organism: &Organism) -> bool {
+        self.entries.iter().any(|e| {
+            let genes = &e.organism.dna.genes;
+            genes.len() == organism.dna.genes.len()
+                && genes.iter().zip(&organism.dna.genes).all(|(a, b)| a.to_bits() == b.to_bits())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::Dna;
+
+    fn organism(id: u64, gene: f32, fitness: f32) -> Organism {
+        Organism { fitness, ..Organism::new(OrganismId(id), Dna::from_genes(vec![gene])) }
+    }
+
+    #[test]
+    fn keeps_the_best_distinct_organisms() {
+        let mut hall = HallOfFame::new(3);
+        assert_eq!(hall.update(&[organism(1, 0.1, 1.0), organism(2, 0.2, 5.0)], 0), 2);
+        // Same genome as #2 under a new id, plus two newcomers.
+        let admitted = hall.update(&[organism(3, 0.2, 5.0), organism(4, 0.4, 3.0), organism(5, 0.5, 0.5)], 4);
+        assert_eq!(admitted, 1);
+
+        let ids: Vec<u64> = hall.entries().iter().map(|e| e.organism.id.0).collect();
+        assert_eq!(ids, vec![2, 4, 1]);
+        assert_eq!(hall.get(&OrganismId(4)).unwrap().generation, 4);
+
+        hall.rescore(|genes| genes[0]);
+        assert_eq!(hall.best().unwrap().organism.id, OrganismId(4));
+    }
+}
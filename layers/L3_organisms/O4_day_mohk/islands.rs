@@ -0,0 +1,300 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Island-model evolution on top of the Phoenix Engine.
+//!
+//! Several independent engines ("islands") evolve side by side. Every
+//! `interval` generations the best organisms of each island migrate to its
+//! neighbours according to the configured topology, replacing their worst
+//! organisms. Immigrants get fresh ids, with the source organism as their
+//! parent, so an id never lives on two islands at once. Islands are keyed
+//! by plain string ids so higher layers can map them onto anything (e.g.
+//! Day-Mohk territories in L6).
+
+use rand::seq::index;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{BlockchainStorage, Organism, OrganismId, PhoenixEngine, QuantumMutator};
+
+/// Which islands exchange organisms during a migration round.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MigrationTopology {
+    /// Island `i` sends to island `i + 1` (wrapping around).
+    Ring,
+    /// Every island sends to every other island.
+    FullyConnected,
+    /// Every island sends to `links` distinct, randomly drawn islands.
+    Random { links: usize },
+}
+
+/// Migration parameters of an [`IslandModel`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MigrationConfig {
+    /// Generations between migration rounds (`0` disables migration).
+    pub interval: u64,
+    /// Number of top organisms sent along each link.
+    pub migrants: usize,
+    pub topology: MigrationTopology,
+}
+
+impl Default for MigrationConfig {
+    fn default() -> Self {
+        Self {
+            interval: 5,
+            migrants: 2,
+            topology: MigrationTopology::Ring,
+        }
+    }
+}
+
+/// A single sub-population.
+pub struct Island<Q, B> {
+    pub id: String,
+    pub engine: PhoenixEngine<Q, B>,
+}
+
+/// Gene flow along one link during a migration round.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MigrationEvent {
+    pub generation: u64,
+    pub from: String,
+    pub to: String,
+    /// Migrants on the source island.
+    pub organism_ids: Vec<OrganismId>,
+    /// Their copies on the destination island.
+    #[serde(default)]
+    pub immigrant_ids: Vec<OrganismId>,
+    /// Best fitness among the migrants.
+    pub best_fitness: f32,
+}
+
+/// Per-island summary for dashboards and the map UI.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IslandStats {
+    pub id: String,
+    pub generation: u64,
+    pub population_size: usize,
+    pub best_fitness: f32,
+    pub mean_fitness: f32,
+}
+
+/// Multi-population evolution with periodic migration.
+pub struct IslandModel<Q, B> {
+    pub islands: Vec<Island<Q, B>>,
+    pub migration: MigrationConfig,
+    pub generation: u64,
+    /// Most recent migration events (at most [`IslandModel::EVENT_CAPACITY`]).
+    pub events: Vec<MigrationEvent>,
+    rng: ChaCha8Rng,
+}
+
+impl<Q, B> IslandModel<Q, B>
+where
+    Q: QuantumMutator,
+    B: BlockchainStorage,
+{
+    /// Number of migration events kept in memory.
+    pub const EVENT_CAPACITY: usize = 500;
+
+    /// Build a model from ready-made islands. `seed` drives the random topology.
+    pub fn new(islands: Vec<Island<Q, B>>, migration: MigrationConfig, seed: u64) -> Self {
+        Self {
+            islands,
+            migration,
+            generation: 0,
+            events: Vec::new(),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Evolve every island by one generation (keeping its population size)
+    /// and run a migration round when the interval is reached.
+    ///
+    /// Returns the migration events of this step (empty if none happened).
+    pub fn evolve(&mut self) -> Vec<MigrationEvent> {
+        for island in &mut self.islands {
+            let size = island.engine.population_size();
+            island.engine.evolve(size);
+        }
+        self.generation += 1;
+
+        let interval = self.migration.interval;
+        if interval == 0 || !self.generation.is_multiple_of(interval) {
+            return Vec::new();
+        }
+
+        let events = self.migrate();
+        self.events.extend(events.iter().cloned());
+        if self.events.len() > Self::EVENT_CAPACITY {
+            let excess = self.events.len() - Self::EVENT_CAPACITY;
+            self.events.drain(..excess);
+        }
+        events
+    }
+
+    /// Exchange the best organisms along every link of the topology.
+    ///
+    /// Emigrants are chosen before any island receives immigrants, so a
+    /// round is independent of the order in which links are processed.
+    fn migrate(&mut self) -> Vec<MigrationEvent> {
+        let n = self.islands.len();
+        if n < 2 || self.migration.migrants == 0 {
+            return Vec::new();
+        }
+
+        let emigrants: Vec<_> = self
+            .islands
+            .iter()
+            .map(|island| {
+                let mut ranked = island.engine.population.clone();
+                ranked.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+                ranked.truncate(self.migration.migrants);
+                ranked
+            })
+            .collect();
+
+        let mut events = Vec::new();
+        for (from, to) in self.links() {
+            let migrants = &emigrants[from];
+            if migrants.is_empty() {
+                continue;
+            }
+
+            let engine = &mut self.islands[to].engine;
+            let immigrants: Vec<Organism> = migrants
+                .iter()
+                .map(|migrant| Organism {
+                    id: OrganismId(self.rng.gen()),
+                    parents: vec![migrant.id.clone()],
+                    birth_generation: engine.generation,
+                    ..migrant.clone()
+                })
+                .collect();
+            for immigrant in &immigrants {
+                engine.genealogy.record(immigrant, Vec::new());
+            }
+            let immigrant_ids = immigrants.iter().map(|o| o.id.clone()).collect();
+
+            let population = &mut engine.population;
+            population.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+            let keep = population.len().saturating_sub(migrants.len());
+            population.truncate(keep);
+            population.extend(immigrants);
+
+            events.push(MigrationEvent {
+                generation: self.generation,
+                from: self.islands[from].id.clone(),
+                to: self.islands[to].id.clone(),
+                organism_ids: migrants.iter().map(|o| o.id.clone()).collect(),
+                immigrant_ids,
+                best_fitness: migrants[0].fitness,
+            });
+        }
+        events
+    }
+
+    /// Directed `(from, to)` island index pairs for the current round.
+    fn links(&mut self) -> Vec<(usize, usize)> {
+        let n = self.islands.len();
+        match self.migration.topology {
+            MigrationTopology::Ring => (0..n).map(|i| (i, (i + 1) % n)).collect(),
+            MigrationTopology::FullyConnected => (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .collect(),
+            MigrationTopology::Random { links } => {
+                let links = links.clamp(1, n - 1);
+                let mut pairs = Vec::with_capacity(n * links);
+                for i in 0..n {
+                    // Draw among the other n-1 islands, skipping `i` itself.
+                    for j in index::sample(&mut self.rng, n - 1, links) {
+                        pairs.push((i, if j >= i { j + 1 } else { j }));
+                    }
+                }
+                pairs
+            }
+        }
+    }
+
+    /// Summary of every island.
+    pub fn stats(&self) -> Vec<IslandStats> {
+        self.islands
+            .iter()
+            .map(|island| {
+                let engine = &island.engine;
+                let n = engine.population_size().max(1) as f32;
+                IslandStats {
+                    id: island.id.clone(),
+                    generation: engine.generation,
+                    population_size: engine.population_size(),
+                    best_fitness: engine.best_fitness(),
+                    mean_fitness: engine.population.iter().map(|o| o.fitness).sum::<f32>() / n,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Derive a distinct, reproducible engine seed for island `index` from a
+/// model-wide `seed`.
+pub fn island_seed(seed: u64, index: usize) -> u64 {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(index as u64 + 1);
+    rng.gen()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l0_quantum::L0QuantumMutator;
+    use crate::layers::l1_chronos::L1ChronosFileStorage;
+    use crate::layers::l3_organisms::o4_day_mohk::fitness::GeneSumFitness;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, Organism};
+
+    fn island(id: &str, gene: f32, seed: u64) -> Island<L0QuantumMutator, L1ChronosFileStorage> {
+        let population = (0..6)
+            .map(|i| Organism::new(OrganismId(seed * 100 + i), Dna::from_genes(vec![gene; 4])))
+            .collect();
+        let quantum = L0QuantumMutator { mutation_rate: 0.0, sigma: 0.1, self_adaptive: false };
+        let storage = L1ChronosFileStorage::new(std::env::temp_dir());
+        Island {
+            id: id.to_string(),
+            engine: PhoenixEngine::new("test", quantum, storage, GeneSumFitness::default(), population)
+                .with_seed(island_seed(9, seed as usize)),
+        }
+    }
+
+    #[test]
+    fn ring_migration_moves_best_organisms_downstream() {
+        let islands = vec![island("rich", 0.9, 1), island("poor", 0.1, 2), island("mid", 0.5, 3)];
+        let mut model = IslandModel::new(
+            islands,
+            MigrationConfig { interval: 2, migrants: 2, topology: MigrationTopology::Ring },
+            9,
+        );
+
+        assert!(model.evolve().is_empty());
+        let events = model.evolve();
+        assert_eq!(events.len(), 3);
+        assert_eq!((events[0].from.as_str(), events[0].to.as_str()), ("rich", "poor"));
+        assert_eq!(model.events.len(), 3);
+
+        let stats = model.stats();
+        assert_eq!(stats[1].population_size, 6);
+        assert!((stats[1].best_fitness - 0.9 * 4.0 * 2.0).abs() < 1e-5);
+
+        // Immigrants are new organisms descending from the migrants.
+        let poor = &model.islands[1].engine;
+        for (source, immigrant) in events[0].organism_ids.iter().zip(&events[0].immigrant_ids) {
+            let record = poor.genealogy.get(immigrant).unwrap();
+            assert_eq!(record.parents, vec![source.clone()]);
+            assert!(poor.population.iter().any(|o| o.id == *immigrant));
+        }
+        let mut ids: Vec<u64> =
+            model.islands.iter().flat_map(|island| island.engine.population.iter().map(|o| o.id.0)).collect();
+        let total = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), total);
+    }
+}
@@ -0,0 +1,80 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Global mutation schedules for the Phoenix Engine.
+//!
+//! A schedule produces a multiplier ("scale") that the engine applies to the
+//! mutator's base `mutation_rate` and `sigma` before every generation. It only
+//! needs the mutator to expose [`MutationParams`], so it works with any L0
+//! implementation.
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::MutationParams;
+
+/// Progress signals handed to the schedule after each generation.
+#[derive(Clone, Copy, Debug)]
+pub struct GenerationFeedback {
+    pub generation: u64,
+    /// Fraction of offspring that beat the better of their two parents.
+    pub success_ratio: f32,
+    /// Generations since the best fitness last improved.
+    pub stagnant_generations: u64,
+}
+
+/// How the global mutation strength evolves over a run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MutationSchedule {
+    /// Base parameters are used unchanged.
+    #[default]
+    Constant,
+    /// Scale falls linearly from 1 to `final_scale` over `generations`.
+    LinearDecay { generations: u64, final_scale: f32 },
+    /// Scale is `rate^generation`, floored at `min_scale`.
+    ExponentialDecay { rate: f32, min_scale: f32 },
+    /// Rechenberg's 1/5th success rule: widen mutations while more than a
+    /// fifth of the offspring improve on their parents, narrow them otherwise.
+    /// `factor` in (0, 1) is the per-generation shrink factor.
+    OneFifthRule { factor: f32, min_scale: f32, max_scale: f32 },
+    /// Multiply mutation strength by `boost` while the best fitness has not
+    /// improved for at least `patience` generations.
+    StagnationBoost { patience: u64, boost: f32 },
+}
+
+impl MutationSchedule {
+    /// Scale to use for the next generation, given the current one.
+    pub fn next_scale(&self, current: f32, feedback: &GenerationFeedback) -> f32 {
+        let g = feedback.generation as f32;
+        let scale = match *self {
+            MutationSchedule::Constant => 1.0,
+            MutationSchedule::LinearDecay { generations, final_scale } => {
+                let t = if generations == 0 { 1.0 } else { (g / generations as f32).min(1.0) };
+                1.0 + (final_scale - 1.0) * t
+            }
+            MutationSchedule::ExponentialDecay { rate, min_scale } => rate.powf(g).max(min_scale),
+            MutationSchedule::OneFifthRule { factor, min_scale, max_scale } => {
+                let factor = factor.clamp(f32::EPSILON, 1.0);
+                let next = if feedback.success_ratio > 0.2 {
+                    current / factor
+                } else if feedback.success_ratio < 0.2 {
+                    current * factor
+                } else {
+                    current
+                };
+                next.clamp(min_scale, max_scale.max(min_scale))
+            }
+            MutationSchedule::StagnationBoost { patience, boost } => {
+                if feedback.stagnant_generations >= patience { boost } else { 1.0 }
+            }
+        };
+
+        if scale.is_finite() && scale >= 0.0 { scale } else { current }
+    }
+
+    /// Apply `scale` to base parameters (the rate is capped at 1).
+    pub fn apply(base: &MutationParams, scale: f32) -> MutationParams {
+        MutationParams {
+            mutation_rate: (base.mutation_rate * scale).clamp(0.0, 1.0),
+            sigma: base.sigma * scale,
+        }
+    }
+}
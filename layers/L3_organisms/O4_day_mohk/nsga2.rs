@@ -0,0 +1,220 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Multi-objective optimization (NSGA-II).
+//!
+//! With an [`ObjectiveEvaluator`] installed
+//! ([`PhoenixEngine::with_objectives`](super::phoenix_engine::PhoenixEngine::with_objectives)),
+//! every organism carries a vector of objectives (all maximized) next to its
+//! scalar fitness. Each generation parents and offspring are merged, sorted
+//! into non-dominated fronts and truncated by crowding distance (Deb et al.,
+//! NSGA-II). The scalar `Organism.fitness` then holds the crowded-comparison
+//! score from [`crowded_fitness`], so the ordinary selection strategies act
+//! as NSGA-II's crowded tournament.
+
+use std::cmp::Ordering;
+
+/// Vector-valued fitness (every objective is maximized).
+pub trait ObjectiveEvaluator: Send + Sync {
+    /// Score a genome on every objective.
+    fn evaluate(&self, genes: &[f32]) -> Vec<f32>;
+}
+
+impl<F> ObjectiveEvaluator for F
+where
+    F: Fn(&[f32]) -> Vec<f32> + Send + Sync,
+{
+    fn evaluate(&self, genes: &[f32]) -> Vec<f32> {
+        self(genes)
+    }
+}
+
+/// Whether `a` Pareto-dominates `b`: no worse in every objective and
+/// better in at least one.
+pub fn dominates(a: &[f32], b: &[f32]) -> bool {
+    let mut better = false;
+    for (x, y) in a.iter().zip(b) {
+        match x.total_cmp(y) {
+            Ordering::Less => return false,
+            Ordering::Greater => better = true,
+            Ordering::Equal => {}
+        }
+    }
+    better
+}
+
+/// Fast non-dominated sort: indices of `objectives` grouped into fronts,
+/// the Pareto front first.
+pub fn non_dominated_sort(objectives: &[&[f32]]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+    let mut dominated_by = vec![0usize; n];
+    let mut dominates_list: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for i in 0..n {
+        for j in i + 1..n {
+            if dominates(objectives[i], objectives[j]) {
+                dominates_list[i].push(j);
+                dominated_by[j] += 1;
+            } else if dominates(objectives[j], objectives[i]) {
+                dominates_list[j].push(i);
+                dominated_by[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut current: Vec<usize> = (0..n).filter(|&i| dominated_by[i] == 0).collect();
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        for &i in &current {
+            for &j in &dominates_list[i] {
+                dominated_by[j] -= 1;
+                if dominated_by[j] == 0 {
+                    next.push(j);
+                }
+            }
+        }
+        next.sort_unstable();
+        fronts.push(current);
+        current = next;
+    }
+    fronts
+}
+
+/// Crowding distance of every member of `front` (same order). Boundary
+/// solutions of each objective get `f32::INFINITY`.
+pub fn crowding_distance(objectives: &[&[f32]], front: &[usize]) -> Vec<f32> {
+    let mut distance = vec![0.0f32; front.len()];
+    if front.len() <= 2 {
+        distance.fill(f32::INFINITY);
+        return distance;
+    }
+
+    let mut order: Vec<usize> = (0..front.len()).collect();
+    for m in 0..objectives[front[0]].len() {
+        let value = |k: usize| objectives[front[k]].get(m).copied().unwrap_or(f32::NEG_INFINITY);
+        order.sort_by(|&a, &b| value(a).total_cmp(&value(b)));
+        let (first, last) = (order[0], order[order.len() - 1]);
+        let range = value(last) - value(first);
+        distance[first] = f32::INFINITY;
+        distance[last] = f32::INFINITY;
+        if range <= 0.0 || !range.is_finite() {
+            continue;
+        }
+        for w in order.windows(3) {
+            distance[w[1]] += (value(w[2]) - value(w[0])) / range;
+        }
+    }
+    distance
+}
+
+/// Front rank (0 = Pareto front) and crowding distance of every index.
+pub fn rank_and_crowding(objectives: &[&[f32]]) -> (Vec<usize>, Vec<f32>) {
+    let mut rank = vec![0usize; objectives.len()];
+    let mut crowding = vec![0.0f32; objectives.len()];
+    for (r, front) in non_dominated_sort(objectives).iter().enumerate() {
+        for (&i, d) in front.iter().zip(crowding_distance(objectives, front)) {
+            rank[i] = r;
+            crowding[i] = d;
+        }
+    }
+    (rank, crowding)
+}
+
+/// Scalar score that orders organisms like NSGA-II's crowded comparison:
+/// a lower rank always wins, and within a rank a larger crowding distance
+/// wins. Pareto-front boundary solutions score 0.
+pub fn crowded_fitness(rank: usize, crowding: f32) -> f32 {
+    -(rank as f32) - 1.0 / (1.0 + crowding)
+}
+
+/// NSGA-II environmental selection: indices of the `count` survivors,
+/// taken front by front and, in the front that does not fit, by
+/// descending crowding distance.
+pub fn select_survivors(objectives: &[&[f32]], count: usize) -> Vec<usize> {
+    let mut survivors = Vec::with_capacity(count);
+    for front in non_dominated_sort(objectives) {
+        let room = count - survivors.len();
+        if room == 0 {
+            break;
+        }
+        if front.len() <= room {
+            survivors.extend(front);
+            continue;
+        }
+        let distance = crowding_distance(objectives, &front);
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| distance[b].total_cmp(&distance[a]).then(front[a].cmp(&front[b])));
+        survivors.extend(order.into_iter().take(room).map(|k| front[k]));
+    }
+    survivors
+}
+
+/// Hypervolume dominated by `points` (maximized objectives) and bounded
+/// below by `reference`. Points that do not beat the reference in every
+/// objective contribute nothing.
+pub fn hypervolume(points: &[&[f32]], reference: &[f32]) -> f64 {
+    let shifted: Vec<Vec<f64>> = points
+        .iter()
+        .filter(|p| p.len() == reference.len() && p.iter().zip(reference).all(|(x, r)| x > r))
+        .map(|p| p.iter().zip(reference).map(|(x, r)| (x - r) as f64).collect())
+        .collect();
+    volume(shifted)
+}
+
+/// Volume dominated by `points` above the origin, sliced along the last
+/// objective.
+fn volume(mut points: Vec<Vec<f64>>) -> f64 {
+    let Some(dims) = points.first().map(Vec::len) else { return 0.0 };
+    if dims == 1 {
+        return points.iter().map(|p| p[0]).fold(0.0, f64::max);
+    }
+
+    let last = dims - 1;
+    points.sort_by(|a, b| b[last].total_cmp(&a[last]));
+    let mut total = 0.0;
+    for i in 0..points.len() {
+        let height = points[i][last] - points.get(i + 1).map_or(0.0, |p| p[last]);
+        if height > 0.0 {
+            let slice = points[..=i].iter().map(|p| p[..last].to_vec()).collect();
+            total += height * volume(slice);
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_fronts_and_measures_them() {
+        let points: Vec<Vec<f32>> = vec![
+            vec![1.0, 4.0], // front 0
+            vec![3.0, 3.0], // front 0
+            vec![4.0, 1.0], // front 0
+            vec![2.0, 2.95], // front 1
+            vec![1.0, 1.0], // front 2
+            vec![2.5, 2.9], // front 1
+        ];
+        let objectives: Vec<&[f32]> = points.iter().map(Vec::as_slice).collect();
+
+        assert!(dominates(&[3.0, 3.0], &[2.0, 2.95]));
+        assert!(!dominates(&[1.0, 4.0], &[4.0, 1.0]));
+        assert_eq!(non_dominated_sort(&objectives), vec![vec![0, 1, 2], vec![3, 5], vec![4]]);
+
+        let crowding = crowding_distance(&objectives, &[0, 1, 2]);
+        assert_eq!(crowding[0], f32::INFINITY);
+        assert!((crowding[1] - 2.0).abs() < 1e-6);
+
+        let (rank, crowding) = rank_and_crowding(&objectives);
+        assert_eq!(rank, vec![0, 0, 0, 1, 2, 1]);
+        assert!(crowded_fitness(rank[1], crowding[1]) > crowded_fitness(rank[3], crowding[3]));
+        assert!(crowded_fitness(rank[0], crowding[0]) > crowded_fitness(rank[1], crowding[1]));
+
+        assert_eq!(select_survivors(&objectives, 4), vec![0, 1, 2, 3]);
+        assert_eq!(select_survivors(&objectives, 2), vec![0, 2]);
+
+        // Union of [0,1]x[0,4], [0,3]x[0,3] and [0,4]x[0,1].
+        assert!((hypervolume(&objectives[..3], &[0.0, 0.0]) - 11.0).abs() < 1e-9);
+        assert!((hypervolume(&[&[1.0, 1.0, 1.0], &[2.0, 0.5, 0.5]], &[0.0; 3]) - 1.25).abs() < 1e-9);
+        assert_eq!(hypervolume(&objectives, &[5.0, 0.0]), 0.0);
+    }
+}
@@ -0,0 +1,511 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Alternative search strategies for the Phoenix Engine.
+//!
+//! The genetic algorithm is the engine's own breed/commit loop. The
+//! optimizers here replace that loop for one generation at a time while
+//! working on the same population of [`Dna`] genomes, with the engine's
+//! RNG, fitness evaluator and parallel scoring. Generation counting,
+//! history and statistics, the hall of fame, L1 checkpoints and rebirth
+//! stay with the engine, so runs of different strategies are directly
+//! comparable and `config.optimizer` can be switched between generations.
+
+use rand::seq::index;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, StandardNormal};
+use serde::{Deserialize, Serialize};
+
+use super::evaluation::ParallelEvaluation;
+use super::phoenix_engine::{Dna, FitnessEvaluator, Organism, OrganismId, QuantumMutator};
+
+/// Search strategy run by [`PhoenixEngine::evolve`](super::phoenix_engine::PhoenixEngine::evolve).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OptimizerKind {
+    /// The engine's genetic algorithm (selection, crossover, L0 mutation).
+    #[default]
+    Genetic,
+    /// DE/rand/1/bin: every organism competes with a trial vector
+    /// `a + weight * (b - c)`, crossed over gene-wise at `crossover_rate`.
+    DifferentialEvolution { weight: f32, crossover_rate: f32 },
+    /// (μ/μ_w, λ)-CMA-ES started at the fittest organism with step size
+    /// `sigma`; λ is the requested offspring count.
+    CmaEs { sigma: f32 },
+    /// One annealing chain per organism, with L0 quantum mutations as the
+    /// neighbourhood move and geometric cooling.
+    SimulatedAnnealing { initial_temperature: f32, cooling: f32 },
+}
+
+impl OptimizerKind {
+    pub fn is_genetic(&self) -> bool {
+        matches!(self, OptimizerKind::Genetic)
+    }
+
+    /// Fresh optimizer state (`None` for the genetic algorithm).
+    pub fn start(&self) -> Option<ActiveOptimizer> {
+        match *self {
+            OptimizerKind::Genetic => None,
+            OptimizerKind::DifferentialEvolution { weight, crossover_rate } => {
+                Some(ActiveOptimizer::DifferentialEvolution(DifferentialEvolution { weight, crossover_rate }))
+            }
+            OptimizerKind::CmaEs { sigma } => Some(ActiveOptimizer::CmaEs(CmaEs::new(sigma))),
+            OptimizerKind::SimulatedAnnealing { initial_temperature, cooling } => {
+                Some(ActiveOptimizer::SimulatedAnnealing(SimulatedAnnealing {
+                    initial_temperature,
+                    cooling,
+                    temperature: initial_temperature,
+                }))
+            }
+        }
+    }
+}
+
+/// What an optimizer gets from the engine for one generation.
+pub struct StepContext<'a, Q> {
+    /// L0 mutator (used by simulated annealing).
+    pub quantum: &'a Q,
+    pub rng: &'a mut ChaCha8Rng,
+    pub fitness: &'a dyn FitnessEvaluator,
+    pub evaluation: ParallelEvaluation,
+    /// Requested offspring count.
+    pub size: usize,
+    /// Generation the new organisms are born into.
+    pub generation: u64,
+}
+
+/// Bookkeeping of one optimizer generation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StepOutcome {
+    pub evaluations: u64,
+    /// Candidates created this generation.
+    pub births: usize,
+    /// Candidates that improved on what they competed against.
+    pub successes: usize,
+}
+
+/// A search strategy that advances a scored population by one generation.
+pub trait Optimizer {
+    /// Configuration this optimizer was started from.
+    fn kind(&self) -> OptimizerKind;
+
+    /// Replace `population` with the next generation. Every organism left
+    /// in `population` is scored.
+    fn step<Q: QuantumMutator>(&mut self, population: &mut Vec<Organism>, ctx: StepContext<'_, Q>) -> StepOutcome;
+}
+
+/// Running optimizer with its state, as kept by the engine and checkpointed to L1.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ActiveOptimizer {
+    DifferentialEvolution(DifferentialEvolution),
+    CmaEs(CmaEs),
+    SimulatedAnnealing(SimulatedAnnealing),
+}
+
+impl Optimizer for ActiveOptimizer {
+    fn kind(&self) -> OptimizerKind {
+        match self {
+            ActiveOptimizer::DifferentialEvolution(o) => o.kind(),
+            ActiveOptimizer::CmaEs(o) => o.kind(),
+            ActiveOptimizer::SimulatedAnnealing(o) => o.kind(),
+        }
+    }
+
+    fn step<Q: QuantumMutator>(&mut self, population: &mut Vec<Organism>, ctx: StepContext<'_, Q>) -> StepOutcome {
+        match self {
+            ActiveOptimizer::DifferentialEvolution(o) => o.step(population, ctx),
+            ActiveOptimizer::CmaEs(o) => o.step(population, ctx),
+            ActiveOptimizer::SimulatedAnnealing(o) => o.step(population, ctx),
+        }
+    }
+}
+
+/// Differential evolution (Storn & Price), DE/rand/1/bin. The population
+/// size is kept; the requested offspring count is ignored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DifferentialEvolution {
+    /// Differential weight `F`.
+    pub weight: f32,
+    /// Binomial crossover probability `CR`.
+    pub crossover_rate: f32,
+}
+
+impl Optimizer for DifferentialEvolution {
+    fn kind(&self) -> OptimizerKind {
+        OptimizerKind::DifferentialEvolution { weight: self.weight, crossover_rate: self.crossover_rate }
+    }
+
+    fn step<Q: QuantumMutator>(&mut self, population: &mut Vec<Organism>, ctx: StepContext<'_, Q>) -> StepOutcome {
+        let n = population.len();
+        if n == 0 {
+            return StepOutcome::default();
+        }
+        let rng = ctx.rng;
+
+        let mut trials = Vec::with_capacity(n);
+        for (i, target) in population.iter().enumerate() {
+            let [a, b, c] = pick_others(rng, n, i);
+            let (xa, xb, xc) = (&population[a].dna.genes, &population[b].dna.genes, &population[c].dna.genes);
+            let mut genes = target.dna.genes.clone();
+            let forced = rng.gen_range(0..genes.len().max(1));
+            for (j, gene) in genes.iter_mut().enumerate() {
+                if j != forced && rng.gen::<f32>() >= self.crossover_rate {
+                    continue;
+                }
+                if let (Some(a), Some(b), Some(c)) = (xa.get(j), xb.get(j), xc.get(j)) {
+                    *gene = (a + self.weight * (b - c)).clamp(0.0, 1.0);
+                }
+            }
+            trials.push(Organism {
+                id: OrganismId(rng.gen()),
+                dna: Dna { genes, strategy: target.dna.strategy.clone() },
+                fitness: f32::NAN,
+                parents: vec![target.id.clone(), population[a].id.clone()],
+                birth_generation: ctx.generation,
+                objectives: Vec::new(),
+            });
+        }
+        ctx.evaluation.evaluate(ctx.fitness, &mut trials);
+
+        let mut successes = 0;
+        for (target, trial) in population.iter_mut().zip(trials) {
+            if trial.fitness >= target.fitness {
+                if trial.fitness > target.fitness {
+                    successes += 1;
+                }
+                *target = trial;
+            }
+        }
+        StepOutcome { evaluations: n as u64, births: n, successes }
+    }
+}
+
+/// Three indices other than `exclude` (distinct when the population allows it).
+fn pick_others(rng: &mut ChaCha8Rng, n: usize, exclude: usize) -> [usize; 3] {
+    if n < 4 {
+        return [0; 3].map(|_| rng.gen_range(0..n));
+    }
+    let picked = index::sample(rng, n - 1, 3);
+    let skip = |k: usize| if k >= exclude { k + 1 } else { k };
+    [skip(picked.index(0)), skip(picked.index(1)), skip(picked.index(2))]
+}
+
+/// Covariance matrix adaptation evolution strategy (Hansen), with
+/// comma selection: the population is replaced by the λ sampled offspring.
+/// Samples are clamped to the gene range \[0, 1\] and every offspring has
+/// the genome length the run started with.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CmaEs {
+    /// Step size the run starts with.
+    pub initial_sigma: f32,
+    /// Created from the population on the first step.
+    state: Option<CmaState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct CmaState {
+    mean: Vec<f64>,
+    sigma: f64,
+    covariance: Vec<Vec<f64>>,
+    path_c: Vec<f64>,
+    path_sigma: Vec<f64>,
+    /// Eigenvectors of the covariance (columns) and square roots of its eigenvalues.
+    basis: Vec<Vec<f64>>,
+    scales: Vec<f64>,
+    iteration: u64,
+}
+
+impl CmaEs {
+    pub fn new(sigma: f32) -> Self {
+        Self { initial_sigma: sigma, state: None }
+    }
+
+    /// Current step size (`None` before the first step).
+    pub fn sigma(&self) -> Option<f64> {
+        self.state.as_ref().map(|s| s.sigma)
+    }
+
+    /// Current distribution mean (`None` before the first step).
+    pub fn mean(&self) -> Option<&[f64]> {
+        self.state.as_ref().map(|s| s.mean.as_slice())
+    }
+}
+
+impl Optimizer for CmaEs {
+    fn kind(&self) -> OptimizerKind {
+        OptimizerKind::CmaEs { sigma: self.initial_sigma }
+    }
+
+    fn step<Q: QuantumMutator>(&mut self, population: &mut Vec<Organism>, ctx: StepContext<'_, Q>) -> StepOutcome {
+        let Some(best) = population.iter().max_by(|a, b| a.fitness.total_cmp(&b.fitness)) else {
+            return StepOutcome::default();
+        };
+        let previous_best = best.fitness;
+        let initial_sigma = self.initial_sigma as f64;
+        let state = self.state.get_or_insert_with(|| CmaState::new(&best.dna.genes, initial_sigma));
+        let n = state.mean.len();
+        if n == 0 {
+            return StepOutcome::default();
+        }
+        let lambda = ctx.size.max(2);
+        let rng = ctx.rng;
+
+        let mut samples = Vec::with_capacity(lambda);
+        let mut offspring = Vec::with_capacity(lambda);
+        for _ in 0..lambda {
+            let z: Vec<f64> = (0..n).map(|_| StandardNormal.sample(rng)).collect();
+            let x: Vec<f64> = (0..n)
+                .map(|i| {
+                    let y: f64 = (0..n).map(|j| state.basis[i][j] * state.scales[j] * z[j]).sum();
+                    (state.mean[i] + state.sigma * y).clamp(0.0, 1.0)
+                })
+                .collect();
+            offspring.push(Organism {
+                id: OrganismId(rng.gen()),
+                birth_generation: ctx.generation,
+                ..Organism::new(OrganismId(0), Dna::from_genes(x.iter().map(|&g| g as f32).collect()))
+            });
+            samples.push(x);
+        }
+        ctx.evaluation.evaluate(ctx.fitness, &mut offspring);
+
+        let score = |o: &Organism| if o.fitness.is_nan() { f32::NEG_INFINITY } else { o.fitness };
+        let mut order: Vec<usize> = (0..lambda).collect();
+        order.sort_by(|&a, &b| score(&offspring[b]).total_cmp(&score(&offspring[a])));
+        let ranked: Vec<&[f64]> = order.iter().map(|&k| samples[k].as_slice()).collect();
+        state.update(&ranked);
+
+        let successes = offspring.iter().filter(|o| o.fitness > previous_best).count();
+        *population = offspring;
+        StepOutcome { evaluations: lambda as u64, births: lambda, successes }
+    }
+}
+
+impl CmaState {
+    fn new(mean: &[f32], sigma: f64) -> Self {
+        let n = mean.len();
+        let identity: Vec<Vec<f64>> =
+            (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+        Self {
+            mean: mean.iter().map(|&g| g as f64).collect(),
+            sigma,
+            covariance: identity.clone(),
+            path_c: vec![0.0; n],
+            path_sigma: vec![0.0; n],
+            basis: identity,
+            scales: vec![1.0; n],
+            iteration: 0,
+        }
+    }
+
+    /// Move the distribution towards `ranked` samples (best first).
+    fn update(&mut self, ranked: &[&[f64]]) {
+        let n = self.mean.len();
+        let nf = n as f64;
+        let mu = (ranked.len() / 2).max(1);
+        let raw: Vec<f64> =
+            (0..mu).map(|i| ((ranked.len() as f64 + 1.0) / 2.0).ln() - ((i + 1) as f64).ln()).collect();
+        let total: f64 = raw.iter().sum();
+        let weights: Vec<f64> = raw.iter().map(|w| w / total).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cc = (4.0 + mueff / nf) / (nf + 4.0 + 2.0 * mueff / nf);
+        let cs = (mueff + 2.0) / (nf + mueff + 5.0);
+        let c1 = 2.0 / ((nf + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((nf + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+
+        let old_mean = std::mem::take(&mut self.mean);
+        self.mean = (0..n).map(|i| weights.iter().zip(ranked).map(|(w, x)| w * x[i]).sum()).collect();
+        let step: Vec<f64> = (0..n).map(|i| (self.mean[i] - old_mean[i]) / self.sigma).collect();
+
+        // C^{-1/2} * step = B * D^{-1} * B^T * step
+        let rotated: Vec<f64> =
+            (0..n).map(|j| (0..n).map(|i| self.basis[i][j] * step[i]).sum::<f64>() / self.scales[j]).collect();
+        let whitened: Vec<f64> = (0..n).map(|i| (0..n).map(|j| self.basis[i][j] * rotated[j]).sum()).collect();
+        let ps_gain = (cs * (2.0 - cs) * mueff).sqrt();
+        for (p, w) in self.path_sigma.iter_mut().zip(&whitened) {
+            *p = (1.0 - cs) * *p + ps_gain * w;
+        }
+        let ps_norm = self.path_sigma.iter().map(|p| p * p).sum::<f64>().sqrt();
+        self.iteration += 1;
+        let decay = 1.0 - (1.0 - cs).powf(2.0 * self.iteration as f64);
+        let hsig = ps_norm / decay.sqrt() / chi_n < 1.4 + 2.0 / (nf + 1.0);
+        let hsig = if hsig { 1.0 } else { 0.0 };
+        let pc_gain = hsig * (cc * (2.0 - cc) * mueff).sqrt();
+        for (p, s) in self.path_c.iter_mut().zip(&step) {
+            *p = (1.0 - cc) * *p + pc_gain * s;
+        }
+
+        let deviations: Vec<Vec<f64>> =
+            ranked.iter().take(mu).map(|x| (0..n).map(|i| (x[i] - old_mean[i]) / self.sigma).collect()).collect();
+        let keep = 1.0 - c1 - cmu;
+        let correction = (1.0 - hsig) * cc * (2.0 - cc);
+        for i in 0..n {
+            for j in 0..=i {
+                let rank_mu: f64 = weights.iter().zip(&deviations).map(|(w, y)| w * y[i] * y[j]).sum();
+                let value = keep * self.covariance[i][j]
+                    + c1 * (self.path_c[i] * self.path_c[j] + correction * self.covariance[i][j])
+                    + cmu * rank_mu;
+                self.covariance[i][j] = value;
+                self.covariance[j][i] = value;
+            }
+        }
+
+        self.sigma *= ((cs / damps) * (ps_norm / chi_n - 1.0)).exp();
+        self.sigma = if self.sigma.is_finite() { self.sigma.clamp(1e-10, 1.0) } else { 1e-10 };
+
+        let (values, vectors) = symmetric_eigen(&self.covariance);
+        self.scales = values.iter().map(|v| v.max(1e-20).sqrt()).collect();
+        self.basis = vectors;
+    }
+}
+
+/// Eigenvalues and eigenvectors (as columns) of a symmetric matrix, by
+/// cyclic Jacobi rotations.
+fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut v: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect();
+    for _ in 0..64 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        if off < 1e-22 {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    (*pk, *qk) = (c * *pk - s * *qk, s * *pk + c * *qk);
+                }
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
+
+/// Simulated annealing with one chain per organism. Each generation every
+/// organism proposes a neighbour by L0 quantum mutation and moves to it if
+/// it is fitter, or with probability `exp(Δ / temperature)` otherwise. The
+/// population size is kept; the requested offspring count is ignored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimulatedAnnealing {
+    pub initial_temperature: f32,
+    /// Per-generation temperature factor in (0, 1).
+    pub cooling: f32,
+    pub temperature: f32,
+}
+
+impl Optimizer for SimulatedAnnealing {
+    fn kind(&self) -> OptimizerKind {
+        OptimizerKind::SimulatedAnnealing { initial_temperature: self.initial_temperature, cooling: self.cooling }
+    }
+
+    fn step<Q: QuantumMutator>(&mut self, population: &mut Vec<Organism>, ctx: StepContext<'_, Q>) -> StepOutcome {
+        let rng = ctx.rng;
+        let mut neighbours: Vec<Organism> = population
+            .iter()
+            .map(|current| {
+                let mut dna = current.dna.clone();
+                ctx.quantum.quantum_mutate(&mut dna, rng);
+                Organism {
+                    id: OrganismId(rng.gen()),
+                    parents: vec![current.id.clone()],
+                    birth_generation: ctx.generation,
+                    ..Organism::new(OrganismId(0), dna)
+                }
+            })
+            .collect();
+        ctx.evaluation.evaluate(ctx.fitness, &mut neighbours);
+
+        let births = neighbours.len();
+        let mut successes = 0;
+        for (current, neighbour) in population.iter_mut().zip(neighbours) {
+            let delta = neighbour.fitness - current.fitness;
+            let accept =
+                delta >= 0.0 || (self.temperature > 0.0 && rng.gen::<f32>() < (delta / self.temperature).exp());
+            if delta > 0.0 {
+                successes += 1;
+            }
+            if accept {
+                *current = neighbour;
+            }
+        }
+        self.temperature *= self.cooling.clamp(0.0, 1.0);
+        StepOutcome { evaluations: births as u64, births, successes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    struct NoMutation;
+
+    impl QuantumMutator for NoMutation {
+        fn quantum_mutate<R: Rng + ?Sized>(&self, _dna: &mut Dna, _rng: &mut R) {}
+    }
+
+    #[test]
+    fn jacobi_rotations_diagonalize_symmetric_matrices() {
+        let m = vec![vec![4.0, 1.0, 0.5], vec![1.0, 3.0, 0.2], vec![0.5, 0.2, 1.0]];
+        let (values, vectors) = symmetric_eigen(&m);
+        for (i, row) in m.iter().enumerate() {
+            for j in 0..3 {
+                let rebuilt: f64 = (0..3).map(|k| vectors[i][k] * values[k] * vectors[j][k]).sum();
+                assert!((rebuilt - row[j]).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn cma_es_converges_on_a_shifted_sphere() {
+        let fitness = |genes: &[f32]| -genes.iter().map(|g| (g - 0.3) * (g - 0.3)).sum::<f32>();
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut population: Vec<Organism> = (0..12)
+            .map(|i| {
+                let mut o = Organism::new(OrganismId(i), Dna::new_random(5, &mut rng));
+                o.fitness = fitness(&o.dna.genes);
+                o
+            })
+            .collect();
+
+        let mut cma = CmaEs::new(0.3);
+        for generation in 1..=120 {
+            let ctx = StepContext {
+                quantum: &NoMutation,
+                rng: &mut rng,
+                fitness: &fitness,
+                evaluation: ParallelEvaluation::default(),
+                size: 12,
+                generation,
+            };
+            cma.step(&mut population, ctx);
+        }
+        let mean = cma.mean().expect("started");
+        assert!(mean.iter().all(|m| (m - 0.3).abs() < 1e-3), "{mean:?}");
+        assert!(cma.sigma().expect("started") < 0.01);
+    }
+}
@@ -0,0 +1,422 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Packed (struct-of-arrays) populations.
+//!
+//! A `Vec<Organism>` costs one gene allocation per organism and scatters
+//! genomes across the heap. [`PackedPopulation`] keeps ids, fitness and
+//! genes in three flat buffers instead, with genomes of one fixed length
+//! stored back to back, optionally quantized to `u16` or `u8` per gene.
+//! [`OrganismView`]s index into the buffers, and convert to and from the
+//! regular [`Organism`] API where needed.
+//!
+//! Packed populations are evolved by
+//! [`PhoenixEngine::evolve_packed`](super::phoenix_engine::PhoenixEngine::evolve_packed).
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use rand::Rng;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::evaluation::ParallelEvaluation;
+use super::phoenix_engine::{Dna, FitnessEvaluator, Organism, OrganismId};
+use super::statistics::{self, PopulationStats, DIVERSITY_SAMPLE};
+
+/// Storage type of each gene. Quantized encodings cover genes in `[0, 1]`
+/// (the range of random genomes and L0 mutations); values outside it are
+/// clamped when stored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeneEncoding {
+    /// Full precision, 4 bytes per gene.
+    #[default]
+    F32,
+    /// 2 bytes per gene, step `1 / 65535`.
+    U16,
+    /// 1 byte per gene, step `1 / 255`.
+    U8,
+}
+
+impl GeneEncoding {
+    pub fn bytes_per_gene(self) -> usize {
+        match self {
+            GeneEncoding::F32 => 4,
+            GeneEncoding::U16 => 2,
+            GeneEncoding::U8 => 1,
+        }
+    }
+
+    /// Largest difference between a gene in `[0, 1]` and its stored value.
+    pub fn max_error(self) -> f32 {
+        match self {
+            GeneEncoding::F32 => 0.0,
+            GeneEncoding::U16 => 0.5 / u16::MAX as f32,
+            GeneEncoding::U8 => 0.5 / u8::MAX as f32,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum GeneBuffer {
+    F32(Vec<f32>),
+    U16(Vec<u16>),
+    U8(Vec<u8>),
+}
+
+impl GeneBuffer {
+    fn new(encoding: GeneEncoding, capacity: usize) -> Self {
+        match encoding {
+            GeneEncoding::F32 => GeneBuffer::F32(Vec::with_capacity(capacity)),
+            GeneEncoding::U16 => GeneBuffer::U16(Vec::with_capacity(capacity)),
+            GeneEncoding::U8 => GeneBuffer::U8(Vec::with_capacity(capacity)),
+        }
+    }
+
+    fn encoding(&self) -> GeneEncoding {
+        match self {
+            GeneBuffer::F32(_) => GeneEncoding::F32,
+            GeneBuffer::U16(_) => GeneEncoding::U16,
+            GeneBuffer::U8(_) => GeneEncoding::U8,
+        }
+    }
+
+    fn extend(&mut self, genes: &[f32]) {
+        match self {
+            GeneBuffer::F32(buf) => buf.extend_from_slice(genes),
+            GeneBuffer::U16(buf) => {
+                buf.extend(genes.iter().map(|g| (g.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16))
+            }
+            GeneBuffer::U8(buf) => {
+                buf.extend(genes.iter().map(|g| (g.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8))
+            }
+        }
+    }
+
+    /// Decode `start..start + len` into `out` (replacing its contents).
+    fn decode_into(&self, start: usize, len: usize, out: &mut Vec<f32>) {
+        out.clear();
+        match self {
+            GeneBuffer::F32(buf) => out.extend_from_slice(&buf[start..start + len]),
+            GeneBuffer::U16(buf) => {
+                out.extend(buf[start..start + len].iter().map(|&q| q as f32 / u16::MAX as f32))
+            }
+            GeneBuffer::U8(buf) => {
+                out.extend(buf[start..start + len].iter().map(|&q| q as f32 / u8::MAX as f32))
+            }
+        }
+    }
+
+    fn hash_range(&self, start: usize, len: usize, state: &mut DefaultHasher) {
+        match self {
+            GeneBuffer::F32(buf) => buf[start..start + len].iter().for_each(|g| g.to_bits().hash(state)),
+            GeneBuffer::U16(buf) => buf[start..start + len].hash(state),
+            GeneBuffer::U8(buf) => buf[start..start + len].hash(state),
+        }
+    }
+}
+
+/// Population with ids, fitness and genes in contiguous buffers.
+/// Every genome has exactly `genome_len` genes.
+#[derive(Clone, Debug)]
+pub struct PackedPopulation {
+    genome_len: usize,
+    ids: Vec<u64>,
+    fitness: Vec<f32>,
+    genes: GeneBuffer,
+}
+
+/// Organisms scored per task in [`PackedPopulation::evaluate_from`].
+const EVALUATION_CHUNK: usize = 1_024;
+
+impl PackedPopulation {
+    pub fn new(genome_len: usize, encoding: GeneEncoding) -> Self {
+        Self::with_capacity(genome_len, encoding, 0)
+    }
+
+    pub fn with_capacity(genome_len: usize, encoding: GeneEncoding, organisms: usize) -> Self {
+        Self {
+            genome_len,
+            ids: Vec::with_capacity(organisms),
+            fitness: Vec::with_capacity(organisms),
+            genes: GeneBuffer::new(encoding, organisms * genome_len),
+        }
+    }
+
+    /// `count` random organisms with ids `0..count` (unscored).
+    pub fn random(count: usize, genome_len: usize, encoding: GeneEncoding, rng: &mut impl Rng) -> Self {
+        let mut population = Self::with_capacity(genome_len, encoding, count);
+        let mut genes = Vec::with_capacity(genome_len);
+        for id in 0..count as u64 {
+            genes.clear();
+            genes.extend((0..genome_len).map(|_| rng.gen::<f32>()));
+            population.push(OrganismId(id), &genes, 0.0);
+        }
+        population
+    }
+
+    /// Pack `organisms`, which must all have the same genome length.
+    /// Per-genome mutation strategies, parents and birth generations are
+    /// not kept.
+    pub fn from_organisms(organisms: &[Organism], encoding: GeneEncoding) -> Result<Self, String> {
+        let genome_len = organisms.first().map_or(0, |o| o.dna.genes.len());
+        let mut population = Self::with_capacity(genome_len, encoding, organisms.len());
+        for organism in organisms {
+            if organism.dna.genes.len() != genome_len {
+                return Err(format!(
+                    "organism {} has {} genes, expected {genome_len}",
+                    organism.id.0,
+                    organism.dna.genes.len()
+                ));
+            }
+            population.push(organism.id.clone(), &organism.dna.genes, organism.fitness);
+        }
+        Ok(population)
+    }
+
+    /// Unpack into regular organisms.
+    pub fn to_organisms(&self) -> Vec<Organism> {
+        self.iter().map(|o| o.to_organism()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn genome_len(&self) -> usize {
+        self.genome_len
+    }
+
+    pub fn encoding(&self) -> GeneEncoding {
+        self.genes.encoding()
+    }
+
+    /// Fitness of every organism, in index order.
+    pub fn fitness(&self) -> &[f32] {
+        &self.fitness
+    }
+
+    /// Bytes held by the gene buffer.
+    pub fn gene_bytes(&self) -> usize {
+        self.len() * self.genome_len * self.encoding().bytes_per_gene()
+    }
+
+    /// Append an organism. `genes` must be `genome_len` long.
+    pub fn push(&mut self, id: OrganismId, genes: &[f32], fitness: f32) {
+        assert_eq!(genes.len(), self.genome_len, "packed genomes have a fixed length");
+        self.ids.push(id.0);
+        self.fitness.push(fitness);
+        self.genes.extend(genes);
+    }
+
+    /// View of organism `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn get(&self, index: usize) -> OrganismView<'_> {
+        assert!(index < self.len(), "organism index {index} out of bounds");
+        OrganismView { population: self, index }
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = OrganismView<'_>> {
+        (0..self.len()).map(move |index| OrganismView { population: self, index })
+    }
+
+    /// Decode the genes of organism `index` into `out`.
+    pub fn decode_into(&self, index: usize, out: &mut Vec<f32>) {
+        self.genes.decode_into(index * self.genome_len, self.genome_len, out);
+    }
+
+    /// Indices of the `k` fittest organisms, best first.
+    pub fn best(&self, k: usize) -> Vec<usize> {
+        let k = k.min(self.len());
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        let by_fitness = |a: &usize, b: &usize| self.fitness[*b].total_cmp(&self.fitness[*a]);
+        if k < order.len() {
+            order.select_nth_unstable_by(k - 1, by_fitness);
+            order.truncate(k);
+        }
+        order.sort_by(by_fitness);
+        order
+    }
+
+    /// Score every organism from `start` on, in parallel per `parallel`.
+    pub fn evaluate_from(&mut self, start: usize, fitness: &dyn FitnessEvaluator, parallel: ParallelEvaluation) {
+        let len = self.genome_len;
+        let genes = &self.genes;
+        let score = |first: usize, out: &mut [f32]| {
+            let mut scratch = Vec::with_capacity(len);
+            for (k, f) in out.iter_mut().enumerate() {
+                genes.decode_into((first + k) * len, len, &mut scratch);
+                *f = fitness.evaluate(&scratch);
+            }
+        };
+
+        let targets = &mut self.fitness[start.min(self.ids.len())..];
+        if parallel.enabled && targets.len() >= parallel.min_batch.max(2) {
+            targets
+                .par_chunks_mut(EVALUATION_CHUNK)
+                .enumerate()
+                .for_each(|(c, out)| score(start + c * EVALUATION_CHUNK, out));
+        } else {
+            score(start, targets);
+        }
+    }
+
+    /// Statistics matching [`statistics::population_stats`] of the unpacked
+    /// population (distinct genotypes are counted by hash).
+    pub fn stats(&self) -> PopulationStats {
+        if self.is_empty() {
+            return PopulationStats::default();
+        }
+
+        let step = self.len().div_ceil(DIVERSITY_SAMPLE).max(1);
+        let sample: Vec<Organism> = (0..self.len()).step_by(step).map(|i| self.get(i).to_organism()).collect();
+        let diversity = statistics::population_stats(&sample).diversity;
+
+        let mut fitness: Vec<f32> = self.fitness.iter().copied().filter(|f| f.is_finite()).collect();
+        fitness.sort_unstable_by(f32::total_cmp);
+        let (best, mean, median, worst, std_dev) = if fitness.is_empty() {
+            (0.0, 0.0, 0.0, 0.0, 0.0)
+        } else {
+            let n = fitness.len();
+            let mean = (fitness.iter().map(|&f| f as f64).sum::<f64>() / n as f64) as f32;
+            let median = if n % 2 == 1 {
+                fitness[n / 2]
+            } else {
+                (fitness[n / 2 - 1] + fitness[n / 2]) / 2.0
+            };
+            let variance = fitness.iter().map(|&f| ((f - mean) as f64).powi(2)).sum::<f64>() / n as f64;
+            (fitness[n - 1], mean, median, fitness[0], variance.sqrt() as f32)
+        };
+
+        let mut sum = vec![0.0f64; self.genome_len];
+        let mut sum_sq = vec![0.0f64; self.genome_len];
+        let mut scratch = Vec::with_capacity(self.genome_len);
+        let mut genotypes = HashSet::with_capacity(self.len());
+        for index in 0..self.len() {
+            self.decode_into(index, &mut scratch);
+            for (i, &g) in scratch.iter().enumerate() {
+                sum[i] += g as f64;
+                sum_sq[i] += (g as f64) * (g as f64);
+            }
+            let mut hasher = DefaultHasher::new();
+            self.genes.hash_range(index * self.genome_len, self.genome_len, &mut hasher);
+            genotypes.insert(hasher.finish());
+        }
+        let n = self.len() as f64;
+        let (gene_means, gene_variances) = sum
+            .iter()
+            .zip(&sum_sq)
+            .map(|(s, sq)| {
+                let mean = s / n;
+                (mean as f32, (sq / n - mean * mean).max(0.0) as f32)
+            })
+            .unzip();
+
+        PopulationStats {
+            size: self.len(),
+            best,
+            mean,
+            median,
+            worst,
+            std_dev,
+            gene_means,
+            gene_variances,
+            diversity,
+            unique_genotypes: genotypes.len(),
+        }
+    }
+}
+
+/// Borrowed view of one organism of a [`PackedPopulation`].
+#[derive(Clone, Copy, Debug)]
+pub struct OrganismView<'a> {
+    population: &'a PackedPopulation,
+    index: usize,
+}
+
+impl<'a> OrganismView<'a> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn id(&self) -> OrganismId {
+        OrganismId(self.population.ids[self.index])
+    }
+
+    pub fn fitness(&self) -> f32 {
+        self.population.fitness[self.index]
+    }
+
+    /// Genes of the organism: borrowed for `f32` storage, decoded otherwise.
+    pub fn genes(&self) -> Cow<'a, [f32]> {
+        let len = self.population.genome_len;
+        match &self.population.genes {
+            GeneBuffer::F32(buf) => Cow::Borrowed(&buf[self.index * len..(self.index + 1) * len]),
+            _ => {
+                let mut genes = Vec::with_capacity(len);
+                self.population.decode_into(self.index, &mut genes);
+                Cow::Owned(genes)
+            }
+        }
+    }
+
+    pub fn to_organism(&self) -> Organism {
+        Organism {
+            fitness: self.fitness(),
+            ..Organism::new(self.id(), Dna::from_genes(self.genes().into_owned()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn packs_quantizes_and_scores_like_organisms() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let organisms: Vec<Organism> = (0..300)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(5, &mut rng)))
+            .collect();
+        let sum = |genes: &[f32]| genes.iter().sum::<f32>();
+
+        let mut exact = PackedPopulation::from_organisms(&organisms, GeneEncoding::F32).unwrap();
+        exact.evaluate_from(0, &sum, ParallelEvaluation { enabled: true, min_batch: 1 });
+        let unpacked = exact.to_organisms();
+        assert_eq!(unpacked[7].dna.genes, organisms[7].dna.genes);
+        assert_eq!(unpacked[7].fitness, sum(&organisms[7].dna.genes));
+        let expected = statistics::population_stats(&unpacked);
+        let stats = exact.stats();
+        assert_eq!((stats.best, stats.worst, stats.median), (expected.best, expected.worst, expected.median));
+        assert_eq!((stats.diversity, stats.unique_genotypes), (expected.diversity, 300));
+
+        for encoding in [GeneEncoding::U16, GeneEncoding::U8] {
+            let packed = PackedPopulation::from_organisms(&organisms, encoding).unwrap();
+            assert_eq!(packed.gene_bytes(), 300 * 5 * encoding.bytes_per_gene());
+            for (view, organism) in packed.iter().zip(&organisms) {
+                assert_eq!(view.id(), organism.id);
+                for (q, g) in view.genes().iter().zip(&organism.dna.genes) {
+                    assert!((q - g).abs() <= encoding.max_error() + f32::EPSILON);
+                }
+            }
+        }
+
+        let best = exact.best(3);
+        assert_eq!(exact.get(best[0]).fitness(), expected.best);
+        assert!(exact.fitness()[best[1]] >= exact.fitness()[best[2]]);
+
+        let ragged = vec![organisms[0].clone(), Organism::new(OrganismId(9), Dna::from_genes(vec![0.5]))];
+        assert!(PackedPopulation::from_organisms(&ragged, GeneEncoding::U8).is_err());
+    }
+}
@@ -5,11 +5,32 @@
 //! with:
 //! - L0 (quantum) for mutation primitives
 //! - L1 (chronos/blockchain) for temporal snapshots and recovery.
+//!
+//! Fitness is owned by L3 itself: every engine carries a [`FitnessEvaluator`]
+//! (see `fitness.rs` for the built-in ones) and scores organisms on its own.
 
 use std::fmt;
+use std::sync::Arc;
 
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 
+use super::crossover::{Crossover, CrossoverOperator};
+use super::evaluation::{Brood, ParallelEvaluation};
+use super::packed::PackedPopulation;
+use super::mutation_schedule::{GenerationFeedback, MutationSchedule};
+use super::nsga2::{self, ObjectiveEvaluator};
+use super::optimizers::{ActiveOptimizer, Optimizer, OptimizerKind, StepContext};
+use super::structural::StructuralMutation;
+use super::genealogy::{Genealogy, MutationRecord};
+use super::statistics::{self, PopulationStats};
+use super::hall_of_fame::HallOfFame;
+use super::rebirth::{CollapseCause, RebirthCheckpoint, RebirthEvent, RebirthPolicy};
+use super::selection::SelectionStrategy;
+use super::speciation::{self, Niching};
+use super::timeline::ForkOrigin;
+
 /// Unique identifier of an organism inside the simulation.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -25,12 +46,19 @@ pub struct OrganismId(pub u64);
 pub struct Dna {
     /// Continuous-valued genes in \[0.0, 1.0\].
     pub genes: Vec<f32>,
+    /// Self-adaptive mutation parameters that evolve with the genome
+    /// (`None` until a self-adaptive mutator first touches it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<MutationParams>,
 }
 
 impl Dna {
+    pub fn from_genes(genes: Vec<f32>) -> Self {
+        Self { genes, strategy: None }
+    }
+
     pub fn new_random(len: usize, rng: &mut impl rand::Rng) -> Self {
-        let genes = (0..len).map(|_| rng.gen()).collect();
-        Self { genes }
+        Self::from_genes((0..len).map(|_| rng.gen()).collect())
     }
 }
 
@@ -41,6 +69,36 @@ pub struct Organism {
     pub dna: Dna,
     /// Cached fitness score for the last evaluation.
     pub fitness: f32,
+    /// Ids of the two parents (empty for founders).
+    #[serde(default)]
+    pub parents: Vec<OrganismId>,
+    /// Generation in which the organism was bred (0 for founders).
+    #[serde(default)]
+    pub birth_generation: u64,
+    /// Objective vector in multi-objective mode (empty otherwise).
+    #[serde(default)]
+    pub objectives: Vec<f32>,
+}
+
+impl Organism {
+    /// An unscored founder organism.
+    pub fn new(id: OrganismId, dna: Dna) -> Self {
+        Self {
+            id,
+            dna,
+            fitness: 0.0,
+            parents: Vec::new(),
+            birth_generation: 0,
+            objectives: Vec::new(),
+        }
+    }
+}
+
+/// Tunable parameters of a mutation operator, persisted with checkpoints.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MutationParams {
+    pub mutation_rate: f32,
+    pub sigma: f32,
 }
 
 /// Trait that must be implemented by the L0 quantum layer.
@@ -49,7 +107,107 @@ pub struct Organism {
 /// operators (e.g. sampling from simulated quantum circuits).
 pub trait QuantumMutator {
     /// Perform an in‑place quantum mutation on the provided DNA.
-    fn quantum_mutate(&self, dna: &mut Dna);
+    ///
+    /// Implementations must draw all randomness from `rng` so that seeded
+    /// runs are reproducible.
+    fn quantum_mutate<R: Rng + ?Sized>(&self, dna: &mut Dna, rng: &mut R);
+
+    /// Current parameters, if the mutator has any worth checkpointing.
+    fn parameters(&self) -> Option<MutationParams> {
+        None
+    }
+
+    /// Restore parameters previously returned by [`QuantumMutator::parameters`].
+    fn set_parameters(&mut self, _params: &MutationParams) {}
+}
+
+/// Fitness function used by the engine to score organisms (higher is better).
+///
+/// Evaluators receive the raw genes so they can be shared between every entry
+/// point (API, background loop, examples) without caring about the organism
+/// wrapper around them.
+pub trait FitnessEvaluator: Send + Sync {
+    /// Score a genome.
+    fn evaluate(&self, genes: &[f32]) -> f32;
+}
+
+impl<F> FitnessEvaluator for F
+where
+    F: Fn(&[f32]) -> f32 + Send + Sync,
+{
+    fn evaluate(&self, genes: &[f32]) -> f32 {
+        self(genes)
+    }
+}
+
+/// One entry of the engine's fitness history.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    pub generation: u64,
+    pub best_fitness: f32,
+    /// Number of species at `EvolutionConfig::species_threshold`.
+    #[serde(default)]
+    pub species_count: usize,
+    /// Species sizes, largest founder fitness first.
+    #[serde(default)]
+    pub species_sizes: Vec<usize>,
+    /// Mutation parameters that produced this generation (mean over genomes
+    /// for self-adaptive mutation; `None` if the mutator exposes none).
+    #[serde(default)]
+    pub mutation: Option<MutationParams>,
+    /// Fitness, gene and diversity statistics of the generation.
+    #[serde(default)]
+    pub stats: PopulationStats,
+    /// Set if the engine was reborn right after this generation.
+    #[serde(default)]
+    pub rebirth: Option<RebirthEvent>,
+    /// Hypervolume of the Pareto front (multi-objective mode only).
+    #[serde(default)]
+    pub hypervolume: Option<f64>,
+}
+
+/// Engine state written to L1 with every checkpoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    /// Seed of the run that produced the snapshot (`None` for legacy checkpoints).
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub population: Vec<Organism>,
+    /// Everything else needed to resume the run exactly (`None` for legacy
+    /// population-only checkpoints).
+    #[serde(default)]
+    pub state: Option<EngineState>,
+}
+
+/// Engine state beyond the population itself.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EngineState {
+    pub generation: u64,
+    /// Position of the engine's ChaCha RNG stream (seeded with `EngineSnapshot::seed`).
+    pub rng_word_pos: u128,
+    /// Base mutator parameters (before the schedule), if the L0 mutator exposes them.
+    pub mutation: Option<MutationParams>,
+    /// Current multiplier of the mutation schedule.
+    #[serde(default = "unit_scale")]
+    pub mutation_scale: f32,
+    pub config: EvolutionConfig,
+    pub history: Vec<HistoryPoint>,
+    /// Fitness evaluations performed so far.
+    #[serde(default)]
+    pub evaluations: u64,
+    #[serde(default)]
+    pub hall_of_fame: HallOfFame,
+    /// Automatic checkpoints available for rebirth.
+    #[serde(default)]
+    pub rebirth_checkpoints: Vec<RebirthCheckpoint>,
+    #[serde(default)]
+    pub last_rebirth: Option<u64>,
+    /// State of a running non-GA optimizer.
+    #[serde(default)]
+    pub optimizer: Option<ActiveOptimizer>,
+    /// Checkpoint this timeline was forked from.
+    #[serde(default)]
+    pub origin: Option<ForkOrigin>,
 }
 
 /// Trait that must be implemented by the L1 blockchain/chronos layer.
@@ -58,15 +216,19 @@ pub trait QuantumMutator {
 pub trait BlockchainStorage {
     type Error: std::error::Error + Send + Sync + 'static;
 
-    /// Load a historical population snapshot from blockchain by `checkpoint_id`.
-    fn load_population(&self, checkpoint_id: &str) -> Result<Vec<Organism>, Self::Error>;
+    /// Load a historical engine snapshot from blockchain by `checkpoint_id`.
+    fn load_snapshot(&self, checkpoint_id: &str) -> Result<EngineSnapshot, Self::Error>;
 
-    /// Store a population snapshot on-chain.
-    fn store_population(
+    /// Store an engine snapshot on-chain.
+    fn store_snapshot(
         &mut self,
         checkpoint_id: &str,
-        population: &[Organism],
+        snapshot: &EngineSnapshot,
     ) -> Result<(), Self::Error>;
+
+    /// Drop a snapshot the engine no longer needs (e.g. an evicted
+    /// automatic checkpoint). Append-only storages may keep it.
+    fn remove_snapshot(&mut self, checkpoint_id: &str) -> Result<(), Self::Error>;
 }
 
 /// Error type local to the Phoenix Engine.
@@ -89,24 +251,258 @@ impl<E: fmt::Display> fmt::Display for PhoenixError<E> {
 
 impl<E: std::error::Error + 'static> std::error::Error for PhoenixError<E> {}
 
+/// Tunable parameters of the evolutionary loop.
+///
+/// Set once when the engine is built ([`PhoenixEngine::with_config`]) and
+/// adjustable at runtime through [`PhoenixEngine::config`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvolutionConfig {
+    /// Parent selection strategy.
+    pub selection: SelectionStrategy,
+    /// Number of top organisms copied unchanged into the next generation.
+    pub elitism: usize,
+    /// Recombination operator applied to selected parents.
+    pub crossover: Crossover,
+    /// Probability that a child is produced by crossover; otherwise it is a
+    /// copy of the first parent (before mutation).
+    pub crossover_rate: f32,
+    /// Diversity pressure (fitness sharing or crowding).
+    pub niching: Niching,
+    /// Maximum genetic distance between an organism and its species'
+    /// representative, used for the species report in the history.
+    pub species_threshold: f32,
+    /// Without niching, report species in the history every this many
+    /// generations (0 = never). Speciation is O(N·S), so it is sampled
+    /// rather than run every generation; with niching it always runs.
+    pub species_interval: u64,
+    /// Global schedule applied to the mutator's base parameters.
+    pub mutation_schedule: MutationSchedule,
+    /// Gene insertion/deletion/duplication applied after point mutation.
+    pub structural: StructuralMutation,
+    /// Automatic checkpointing and rebirth on collapse.
+    pub rebirth: RebirthPolicy,
+    /// Multi-threaded scoring of large populations.
+    pub evaluation: ParallelEvaluation,
+    /// Lower bound of every objective for the hypervolume in multi-objective
+    /// mode (empty = the origin).
+    pub hypervolume_reference: Vec<f32>,
+    /// Search strategy run by [`PhoenixEngine::evolve`]; switching it
+    /// restarts the optimizer on the current population.
+    pub optimizer: OptimizerKind,
+}
+
+impl Default for EvolutionConfig {
+    fn default() -> Self {
+        Self {
+            selection: SelectionStrategy::default(),
+            elitism: 0,
+            crossover: Crossover::default(),
+            crossover_rate: 1.0,
+            niching: Niching::default(),
+            species_threshold: 0.15,
+            species_interval: 0,
+            mutation_schedule: MutationSchedule::default(),
+            structural: StructuralMutation::default(),
+            rebirth: RebirthPolicy::default(),
+            evaluation: ParallelEvaluation::default(),
+            hypervolume_reference: Vec::new(),
+            optimizer: OptimizerKind::default(),
+        }
+    }
+}
+
+fn unit_scale() -> f32 {
+    1.0
+}
+
 /// Core Phoenix Engine.
 ///
 /// Generic over:
 /// - `Q`: provider of quantum mutations from L0
 /// - `B`: blockchain storage from L1
+///
+/// The fitness function is held as a shared trait object so it can be
+/// swapped at runtime without changing the engine type, and handed to a
+/// [`Brood`] that is scored away from the engine.
+///
+/// All randomness of the evolutionary loop comes from an engine-owned RNG
+/// seeded with a recorded `seed`: the same seed, initial population and
+/// parameters replay a bit-identical run.
 pub struct PhoenixEngine<Q, B> {
     pub layer_id: &'static str,
     pub generation: u64,
     pub population: Vec<Organism>,
+    pub config: EvolutionConfig,
+    /// Best fitness per generation (most recent [`PhoenixEngine::HISTORY_CAPACITY`] entries).
+    pub history: Vec<HistoryPoint>,
+    /// Parents, birth generation and mutations of recently born organisms.
+    pub genealogy: Genealogy,
+    /// Best distinct organisms of the whole run.
+    pub hall_of_fame: HallOfFame,
     quantum: Q,
     blockchain: B,
+    fitness: Arc<dyn FitnessEvaluator>,
+    /// Set in multi-objective (NSGA-II) mode.
+    objectives: Option<Arc<dyn ObjectiveEvaluator>>,
+    seed: u64,
+    rng: ChaCha8Rng,
+    /// Mutator parameters the schedule scales (captured at construction).
+    mutation_base: Option<MutationParams>,
+    mutation_scale: f32,
+    evaluations: u64,
+    rebirth_checkpoints: Vec<RebirthCheckpoint>,
+    last_rebirth: Option<u64>,
+    /// Started on the first generation under a non-GA `config.optimizer`.
+    optimizer: Option<ActiveOptimizer>,
+    origin: Option<ForkOrigin>,
+    /// Part of automatic checkpoint IDs, drawn anew for every constructed,
+    /// restored or forked engine so that it never overwrites another
+    /// engine's (or an earlier process's) checkpoints in shared storage.
+    checkpoint_tag: u32,
+    /// Bumped whenever the population, fitness function or config is
+    /// replaced, so a [`Brood`] bred before that is not committed.
+    epoch: u64,
 }
 
 impl<Q, B> PhoenixEngine<Q, B> {
+    /// Number of history points kept in memory (and in checkpoints).
+    pub const HISTORY_CAPACITY: usize = 1_000;
+
     /// Returns the current population size.
     pub fn population_size(&self) -> usize {
         self.population.len()
     }
+
+    /// Seed the engine's RNG was started with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restart the engine's RNG from `seed` (builder style).
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self
+    }
+
+    /// Best fitness in the current population (`0.0` when empty).
+    pub fn best_fitness(&self) -> f32 {
+        self.population
+            .iter()
+            .map(|o| o.fitness)
+            .fold(None, |best: Option<f32>, f| Some(best.map_or(f, |b| b.max(f))))
+            .unwrap_or(0.0)
+    }
+
+    /// Statistics of the current population.
+    pub fn stats(&self) -> PopulationStats {
+        statistics::population_stats(&self.population)
+    }
+
+    /// Re-score every organism with the engine's fitness evaluator (or its
+    /// objectives, ranked by NSGA-II, in multi-objective mode).
+    pub fn evaluate_population(&mut self) {
+        let mut population = std::mem::take(&mut self.population);
+        self.score(&mut population);
+        self.population = population;
+        self.evaluations += self.population.len() as u64;
+        self.rank_population();
+    }
+
+    /// Whether the engine runs in multi-objective (NSGA-II) mode.
+    pub fn is_multi_objective(&self) -> bool {
+        self.objectives.is_some()
+    }
+
+    /// Non-dominated organisms of the current population (empty outside
+    /// multi-objective mode).
+    pub fn pareto_front(&self) -> Vec<&Organism> {
+        if !self.is_multi_objective() {
+            return Vec::new();
+        }
+        let objectives: Vec<&[f32]> = self.population.iter().map(|o| o.objectives.as_slice()).collect();
+        nsga2::non_dominated_sort(&objectives)
+            .first()
+            .map(|front| front.iter().map(|&i| &self.population[i]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Hypervolume of the Pareto front above `config.hypervolume_reference`
+    /// (`None` outside multi-objective mode).
+    pub fn hypervolume(&self) -> Option<f64> {
+        let front = self.pareto_front();
+        let dims = front.first()?.objectives.len();
+        let mut reference = self.config.hypervolume_reference.clone();
+        reference.resize(dims, 0.0);
+        let points: Vec<&[f32]> = front.iter().map(|o| o.objectives.as_slice()).collect();
+        Some(nsga2::hypervolume(&points, &reference))
+    }
+
+    /// Score `organisms`: scalar fitness, or the objective vector in
+    /// multi-objective mode (ranked separately by `rank_population`).
+    fn score(&self, organisms: &mut [Organism]) {
+        match &self.objectives {
+            Some(objectives) => self.config.evaluation.evaluate_objectives(&**objectives, organisms),
+            None => self.config.evaluation.evaluate(&*self.fitness, organisms),
+        }
+    }
+
+    /// In multi-objective mode, set every organism's fitness to its
+    /// crowded-comparison score within the population.
+    fn rank_population(&mut self) {
+        if !self.is_multi_objective() {
+            return;
+        }
+        let objectives: Vec<&[f32]> = self.population.iter().map(|o| o.objectives.as_slice()).collect();
+        let (rank, crowding) = nsga2::rank_and_crowding(&objectives);
+        for (i, organism) in self.population.iter_mut().enumerate() {
+            organism.fitness = nsga2::crowded_fitness(rank[i], crowding[i]);
+        }
+    }
+
+    /// Counter of population, fitness and config replacements; a brood
+    /// bred in another epoch is dropped by [`commit`](Self::commit).
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Replace the evolution parameters of a running engine.
+    pub fn set_config(&mut self, config: EvolutionConfig) {
+        self.config = config;
+        self.epoch += 1;
+    }
+
+    /// Number of fitness evaluations performed so far (re-scoring included).
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations
+    }
+
+    /// Replace the fitness function and re-score the current population.
+    pub fn set_fitness_evaluator(&mut self, fitness: impl FitnessEvaluator + 'static) {
+        self.fitness = Arc::new(fitness);
+        self.evaluate_population();
+        let fitness = &self.fitness;
+        self.hall_of_fame.rescore(|genes| fitness.evaluate(genes));
+        self.epoch += 1;
+    }
+
+    /// Put a copy of hall-of-fame champion `id` back into the population,
+    /// replacing the weakest organism. Returns `false` if `id` is unknown.
+    pub fn reintroduce_champion(&mut self, id: &OrganismId) -> bool {
+        let Some(entry) = self.hall_of_fame.get(id) else { return false };
+        let champion = entry.organism.clone();
+        match self
+            .population
+            .iter_mut()
+            .min_by(|a, b| a.fitness.total_cmp(&b.fitness))
+        {
+            Some(weakest) => *weakest = champion,
+            None => self.population.push(champion),
+        }
+        self.epoch += 1;
+        true
+    }
 }
 
 impl<Q, B> PhoenixEngine<Q, B>
@@ -115,123 +511,743 @@ where
     B: BlockchainStorage,
 {
     /// Construct a new Phoenix Engine from an initial population.
-    pub fn new(layer_id: &'static str, quantum: Q, blockchain: B, population: Vec<Organism>) -> Self {
-        Self {
+    ///
+    /// The initial population is scored with `fitness` right away, so callers
+    /// never have to fill in `Organism.fitness` by hand. The RNG seed is drawn
+    /// from OS entropy; use [`PhoenixEngine::with_seed`] for reproducible runs.
+    pub fn new(
+        layer_id: &'static str,
+        quantum: Q,
+        blockchain: B,
+        fitness: impl FitnessEvaluator + 'static,
+        population: Vec<Organism>,
+    ) -> Self {
+        let seed = rand::random();
+        let mutation_base = quantum.parameters();
+        let mut engine = Self {
             layer_id,
             generation: 0,
             population,
+            config: EvolutionConfig::default(),
+            history: Vec::new(),
+            genealogy: Genealogy::default(),
+            hall_of_fame: HallOfFame::default(),
             quantum,
             blockchain,
-        }
+            fitness: Arc::new(fitness),
+            objectives: None,
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            mutation_base,
+            mutation_scale: 1.0,
+            evaluations: 0,
+            rebirth_checkpoints: Vec::new(),
+            last_rebirth: None,
+            optimizer: None,
+            origin: None,
+            checkpoint_tag: rand::random(),
+            epoch: 0,
+        };
+        engine.evaluate_population();
+        engine.record_population();
+        engine.hall_of_fame.update(&engine.population, 0);
+        engine
     }
 
-    /// Main evolutionary step.
-    ///
-    /// This is intentionally simple and does **not** fix the fitness function –
-    /// caller is responsible for pre‑computing `Organism.fitness` before calling.
+    /// Switch to multi-objective (NSGA-II) mode with `objectives` (builder
+    /// style). The population is re-scored and the hall of fame restarted,
+    /// since scalar fitness now holds crowded-comparison scores.
+    pub fn with_objectives(mut self, objectives: impl ObjectiveEvaluator + 'static) -> Self {
+        self.objectives = Some(Arc::new(objectives));
+        self.evaluate_population();
+        self.hall_of_fame = HallOfFame::new(self.hall_of_fame.capacity);
+        self.hall_of_fame.update(&self.population, self.generation);
+        self.epoch += 1;
+        self
+    }
+
+    /// Replace the evolution parameters (builder style).
+    pub fn with_config(mut self, config: EvolutionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Main evolutionary step: [`breed`](Self::breed), score the brood, then
+    /// [`commit`](Self::commit) it.
     ///
     /// Basic pipeline:
-    /// 1. Сelect fittest parents (tournament selection).
-    /// 2. Crossover DNA.
-    /// 3. Apply quantum mutations from L0.
-    /// 4. Replace population with new offspring.
-    pub fn evolve(&mut self, rng: &mut impl rand::Rng, offspring_count: usize) {
+    /// 1. Carry over the `config.elitism` best organisms unchanged.
+    /// 2. Select parents with `config.selection` (on shared fitness when
+    ///    `config.niching` is fitness sharing).
+    /// 3. Crossover DNA with `config.crossover` (at `config.crossover_rate`).
+    /// 4. Apply quantum mutations from L0.
+    /// 5. Score the offspring with the engine's [`FitnessEvaluator`]
+    ///    (in parallel per `config.evaluation`).
+    /// 6. Replace population with elites + offspring (`offspring_count` total),
+    ///    or let offspring compete for slots when `config.niching` is crowding.
+    ///
+    /// When [`uses_optimizer`](Self::uses_optimizer), the generation is
+    /// produced by `config.optimizer` instead.
+    pub fn evolve(&mut self, offspring_count: usize) {
+        if self.uses_optimizer() {
+            self.optimize(offspring_count);
+        } else if let Some(brood) = self.breed(offspring_count) {
+            self.commit(brood.evaluated());
+        }
+    }
+
+    /// Whether [`evolve`](Self::evolve) runs a non-GA `config.optimizer`.
+    /// Optimizers are single-objective, so multi-objective mode always
+    /// uses the genetic algorithm; [`breed`](Self::breed) and
+    /// [`commit`](Self::commit) always do.
+    pub fn uses_optimizer(&self) -> bool {
+        !self.config.optimizer.is_genetic() && !self.is_multi_objective()
+    }
+
+    /// Running optimizer state, if `config.optimizer` has taken a step.
+    pub fn optimizer(&self) -> Option<&ActiveOptimizer> {
+        self.optimizer.as_ref()
+    }
+
+    /// Checkpoint this engine was forked from (`None` for a root timeline).
+    pub fn origin(&self) -> Option<&ForkOrigin> {
+        self.origin.as_ref()
+    }
+
+    /// One generation of `config.optimizer`, started afresh if the
+    /// configuration changed since its last step.
+    fn optimize(&mut self, offspring_count: usize) {
         if self.population.is_empty() {
+            self.rebirth_if_collapsed();
             return;
         }
+        if self.optimizer.as_ref().map(Optimizer::kind).as_ref() != Some(&self.config.optimizer) {
+            self.optimizer = self.config.optimizer.start();
+        }
+        let Some(optimizer) = self.optimizer.as_mut() else { return };
+        let ctx = StepContext {
+            quantum: &self.quantum,
+            rng: &mut self.rng,
+            fitness: &*self.fitness,
+            evaluation: self.config.evaluation,
+            size: offspring_count,
+            generation: self.generation + 1,
+        };
+        let outcome = optimizer.step(&mut self.population, ctx);
+        self.evaluations += outcome.evaluations;
+        self.record_population();
+        self.finish_generation(self.quantum.parameters(), None, outcome.births, outcome.successes);
+    }
+
+    /// Steps 1–4 of [`evolve`](Self::evolve): breed the next generation's
+    /// offspring without scoring them. Returns `None` if the population is
+    /// empty (an automatic rebirth is attempted instead).
+    ///
+    /// Only breeding draws from the engine RNG, so scoring the brood
+    /// elsewhere keeps seeded runs bit-identical to [`evolve`](Self::evolve).
+    pub fn breed(&mut self, offspring_count: usize) -> Option<Brood> {
+        if self.population.is_empty() {
+            // Nothing to breed from; only an automatic rebirth can help.
+            self.rebirth_if_collapsed();
+            return None;
+        }
+
+        // Crowding and NSGA-II survival keep the best without elites.
+        let elites = match self.config.niching {
+            _ if self.is_multi_objective() => Vec::new(),
+            Niching::Crowding { .. } => Vec::new(),
+            _ => self.elites(offspring_count),
+        };
+        let children = offspring_count - elites.len();
+        // The brood becomes the population unless survivors are picked
+        // against the current one, so it can run due speciation itself.
+        let replaces = !self.is_multi_objective() && !matches!(self.config.niching, Niching::Crowding { .. });
+        let species_threshold =
+            (replaces && self.species_due(self.generation + 1)).then_some(self.config.species_threshold);
+        let rng = &mut self.rng;
 
-        let mut new_population = Vec::with_capacity(offspring_count);
+        let fitness: Vec<f32> = match self.config.niching {
+            Niching::FitnessSharing { sigma, alpha } => {
+                speciation::shared_fitness(&self.population, sigma, alpha)
+            }
+            _ => self.population.iter().map(|o| o.fitness).collect(),
+        };
+        let parents = self.config.selection.select(&fitness, children * 2, rng);
+        let mut brood = Brood {
+            generation: self.generation,
+            epoch: self.epoch,
+            elites,
+            children: Vec::with_capacity(children),
+            mutations: Vec::with_capacity(children),
+            parent_fitness: Vec::with_capacity(children),
+            mutation: self.quantum.parameters(),
+            fitness: Arc::clone(&self.fitness),
+            objectives: self.objectives.clone(),
+            parallel: self.config.evaluation,
+            species_threshold,
+            species_sizes: None,
+            evaluated: false,
+        };
 
-        for _ in 0..offspring_count {
-            let parent_a = self.tournament_select(rng, 3);
-            let parent_b = self.tournament_select(rng, 3);
+        for pair in parents.chunks_exact(2) {
+            let parent_a = &self.population[pair[0]];
+            let parent_b = &self.population[pair[1]];
 
-            let mut child_dna = self.crossover(&parent_a.dna, &parent_b.dna, rng);
+            let mut mutations = Vec::new();
+            let mut child_dna = if rng.gen::<f32>() < self.config.crossover_rate {
+                mutations.push(MutationRecord::Crossover);
+                self.config.crossover.crossover(&parent_a.dna, &parent_b.dna, rng)
+            } else {
+                parent_a.dna.clone()
+            };
 
-            // Quantum mutation from L0.
-            self.quantum.quantum_mutate(&mut child_dna);
+            // Quantum mutation from L0, then length-changing mutations.
+            let before = child_dna.genes.clone();
+            self.quantum.quantum_mutate(&mut child_dna, rng);
+            let changed = before.iter().zip(&child_dna.genes).filter(|(a, b)| a != b).count();
+            if changed > 0 {
+                mutations.push(MutationRecord::Point { genes: changed });
+            }
+            mutations.extend(self.config.structural.mutate(&mut child_dna, rng));
 
             let id = OrganismId(rng.gen());
-            new_population.push(Organism {
+            brood.children.push(Organism {
                 id,
                 dna: child_dna,
-                // Fitness must be evaluated by higher-level layer after evolve().
-                fitness: 0.0,
+                fitness: f32::NAN,
+                parents: vec![parent_a.id.clone(), parent_b.id.clone()],
+                birth_generation: self.generation + 1,
+                objectives: Vec::new(),
             });
+            brood.mutations.push(mutations);
+            brood.parent_fitness.push(parent_a.fitness.max(parent_b.fitness));
         }
+        Some(brood)
+    }
 
-        self.population = new_population;
-        self.generation += 1;
+    /// Step 6 of [`evolve`](Self::evolve): install a brood from
+    /// [`breed`](Self::breed), scoring it first if that has not happened yet.
+    ///
+    /// Returns `false` (and drops the brood) if the engine has moved to
+    /// another generation, or its population, fitness function or config
+    /// was replaced, since the brood was bred.
+    pub fn commit(&mut self, mut brood: Brood) -> bool {
+        if brood.generation != self.generation || brood.epoch != self.epoch {
+            return false;
+        }
+        brood.evaluate();
+        self.evaluations += brood.children.len() as u64;
+
+        let children = brood.children.len();
+        for (child, mutations) in brood.children.iter().zip(brood.mutations) {
+            self.genealogy.record(child, mutations);
+        }
+
+        let successes = if self.is_multi_objective() {
+            // NSGA-II: parents and offspring compete for the next generation.
+            let parents = self.population.len();
+            let mut merged = std::mem::take(&mut self.population);
+            merged.extend(brood.children);
+            let objectives: Vec<&[f32]> = merged.iter().map(|o| o.objectives.as_slice()).collect();
+            let survivors = nsga2::select_survivors(&objectives, children);
+            let mut slots: Vec<Option<Organism>> = merged.into_iter().map(Some).collect();
+            self.population = survivors.iter().filter_map(|&i| slots[i].take()).collect();
+            self.rank_population();
+            survivors.iter().filter(|&&i| i >= parents).count()
+        } else {
+            let successes = brood
+                .children
+                .iter()
+                .zip(&brood.parent_fitness)
+                .filter(|(child, &parent)| child.fitness > parent)
+                .count();
+            let mut new_population = brood.elites;
+            new_population.extend(brood.children);
+            match self.config.niching {
+                Niching::Crowding { factor } => {
+                    speciation::crowding_replace(&mut self.population, new_population, factor, &mut self.rng)
+                }
+                _ => self.population = new_population,
+            }
+            successes
+        };
+        self.finish_generation(brood.mutation, brood.species_sizes, children, successes);
+        true
     }
 
-    /// Restore population state from L1 blockchain.
-    pub fn recover_from_blockchain(
+    /// Shared end of a generation: advance the counter, update the hall of
+    /// fame, history and mutation schedule, then rebirth or checkpoint.
+    fn finish_generation(
         &mut self,
-        checkpoint_id: &str,
-    ) -> Result<(), PhoenixError<B::Error>> {
-        let snapshot = self
-            .blockchain
-            .load_population(checkpoint_id)
-            .map_err(PhoenixError::Blockchain)?;
+        mutation: Option<MutationParams>,
+        species_sizes: Option<Vec<usize>>,
+        children: usize,
+        successes: usize,
+    ) {
+        self.generation += 1;
+        self.hall_of_fame.update(&self.population, self.generation);
+        self.record_history(mutation, species_sizes);
+
+        let feedback = GenerationFeedback {
+            generation: self.generation,
+            success_ratio: if children == 0 { 0.0 } else { successes as f32 / children as f32 },
+            stagnant_generations: self.stagnant_generations(),
+        };
+        self.mutation_scale = self.config.mutation_schedule.next_scale(self.mutation_scale, &feedback);
+        self.apply_mutation_scale();
+
+        if !self.rebirth_if_collapsed() {
+            let interval = self.config.rebirth.checkpoint_interval;
+            if self.config.rebirth.enabled && interval > 0 && self.generation.is_multiple_of(interval) {
+                self.auto_checkpoint();
+            }
+        }
+    }
 
-        if snapshot.is_empty() {
+    /// Evolve a [`PackedPopulation`] by one generation of the same size,
+    /// with the engine's selection, elitism, crossover, L0 mutator, fitness
+    /// and RNG. Genes are decoded into two scratch genomes per child and
+    /// written straight into the next generation's buffer, so there is no
+    /// per-organism allocation to keep.
+    ///
+    /// The generation counter, evaluation count, history (without species),
+    /// hall of fame and mutation schedule advance as in [`evolve`](Self::evolve);
+    /// the engine's own `population` is left alone. Packed runs record no
+    /// genealogy, keep no per-genome strategies and never change genome
+    /// length, so a length-changing `config.crossover` (aligned) is an
+    /// error; `config.niching`, `config.rebirth` and multi-objective mode
+    /// do not apply.
+    pub fn evolve_packed(&mut self, population: &mut PackedPopulation) -> Result<(), PhoenixError<B::Error>> {
+        if !self.config.crossover.preserves_length() {
             return Err(PhoenixError::Engine(format!(
-                "empty snapshot for checkpoint_id={checkpoint_id}"
+                "{:?} crossover changes genome length; packed runs need a fixed length",
+                self.config.crossover
             )));
         }
+        let size = population.len();
+        if size == 0 {
+            return Ok(());
+        }
+        let genome_len = population.genome_len();
+        let elites = population.best(self.config.elitism.min(size));
+        let children = size - elites.len();
+        let rng = &mut self.rng;
+
+        let parents = self.config.selection.select(population.fitness(), children * 2, rng);
+        let mutation = self.quantum.parameters();
+        let mut next = PackedPopulation::with_capacity(genome_len, population.encoding(), size);
+        let mut parent_a = Dna::from_genes(Vec::with_capacity(genome_len));
+        let mut parent_b = Dna::from_genes(Vec::with_capacity(genome_len));
+        for &i in &elites {
+            population.decode_into(i, &mut parent_a.genes);
+            next.push(population.get(i).id(), &parent_a.genes, population.fitness()[i]);
+        }
+
+        let mut parent_fitness = Vec::with_capacity(children);
+        for pair in parents.chunks_exact(2) {
+            population.decode_into(pair[0], &mut parent_a.genes);
+            population.decode_into(pair[1], &mut parent_b.genes);
+            let mut child = if rng.gen::<f32>() < self.config.crossover_rate {
+                self.config.crossover.crossover(&parent_a, &parent_b, rng)
+            } else {
+                parent_a.clone()
+            };
+            self.quantum.quantum_mutate(&mut child, rng);
+
+            next.push(OrganismId(rng.gen()), &child.genes, f32::NAN);
+            parent_fitness.push(population.fitness()[pair[0]].max(population.fitness()[pair[1]]));
+        }
 
-        self.population = snapshot;
+        next.evaluate_from(elites.len(), &*self.fitness, self.config.evaluation);
+        self.evaluations += children as u64;
+        let successes = next.fitness()[elites.len()..]
+            .iter()
+            .zip(&parent_fitness)
+            .filter(|(child, parent)| child > parent)
+            .count();
+        *population = next;
+        self.generation += 1;
+
+        let champions: Vec<Organism> = population
+            .best(self.hall_of_fame.capacity)
+            .into_iter()
+            .map(|i| population.get(i).to_organism())
+            .collect();
+        self.hall_of_fame.update(&champions, self.generation);
+        let stats = population.stats();
+        self.push_history(HistoryPoint {
+            generation: self.generation,
+            best_fitness: stats.best,
+            species_count: 0,
+            species_sizes: Vec::new(),
+            mutation,
+            stats,
+            rebirth: None,
+            hypervolume: None,
+        });
+
+        let feedback = GenerationFeedback {
+            generation: self.generation,
+            success_ratio: if children == 0 { 0.0 } else { successes as f32 / children as f32 },
+            stagnant_generations: self.stagnant_generations(),
+        };
+        self.mutation_scale = self.config.mutation_schedule.next_scale(self.mutation_scale, &feedback);
+        self.apply_mutation_scale();
         Ok(())
     }
 
-    /// Persist current population state to blockchain.
-    pub fn checkpoint_to_blockchain(
-        &mut self,
-        checkpoint_id: &str,
-    ) -> Result<(), PhoenixError<B::Error>> {
-        self.blockchain
-            .store_population(checkpoint_id, &self.population)
-            .map_err(PhoenixError::Blockchain)
+    /// Automatic checkpoints currently available for rebirth (oldest first).
+    pub fn rebirth_checkpoints(&self) -> &[RebirthCheckpoint] {
+        &self.rebirth_checkpoints
+    }
+
+    /// Every rebirth still in the history.
+    pub fn rebirths(&self) -> impl Iterator<Item = &RebirthEvent> {
+        self.history.iter().filter_map(|p| p.rebirth.as_ref())
     }
 
-    /// Simple tournament selection (higher fitness is better).
-    fn tournament_select(&self, rng: &mut impl rand::Rng, k: usize) -> &Organism {
-        use rand::seq::SliceRandom;
+    /// Checkpoint to L1 as a rebirth candidate. Storage errors only mean
+    /// the candidate is not remembered.
+    fn auto_checkpoint(&mut self) {
+        let checkpoint_id = format!("phoenix_auto_{:08x}_{}", self.checkpoint_tag, self.generation);
+        if self.checkpoint_to_blockchain(&checkpoint_id).is_err() {
+            return;
+        }
+        self.rebirth_checkpoints.push(RebirthCheckpoint {
+            checkpoint_id,
+            generation: self.generation,
+            best_fitness: self.best_fitness(),
+        });
+        let keep = self.config.rebirth.keep_checkpoints;
+        if self.rebirth_checkpoints.len() > keep {
+            let excess = self.rebirth_checkpoints.len() - keep;
+            for evicted in self.rebirth_checkpoints.drain(..excess).collect::<Vec<_>>() {
+                if let Err(e) = self.blockchain.remove_snapshot(&evicted.checkpoint_id) {
+                    eprintln!("[Phoenix] failed to remove checkpoint {}: {e}", evicted.checkpoint_id);
+                }
+            }
+        }
+    }
 
-        let k = k.min(self.population.len()).max(1);
-        let mut best: Option<&Organism> = None;
+    /// Reseed the population if the rebirth policy detects a collapse.
+    /// Returns whether a rebirth happened.
+    pub fn rebirth_if_collapsed(&mut self) -> bool {
+        let cause = self
+            .config
+            .rebirth
+            .detect(&self.history, self.population.len(), self.last_rebirth);
+        match cause {
+            Some(cause) => self.rebirth(cause),
+            None => false,
+        }
+    }
 
-        for _ in 0..k {
-            let candidate = self
-                .population
-                .choose(rng)
-                .expect("population is non-empty; guarded above");
+    /// Rise from the fittest loadable automatic checkpoint (or the hall of
+    /// fame if none loads), replacing `immigrant_fraction` of the population
+    /// with random organisms.
+    fn rebirth(&mut self, cause: CollapseCause) -> bool {
+        let mut candidates = self.rebirth_checkpoints.clone();
+        candidates.sort_by(|a, b| b.best_fitness.total_cmp(&a.best_fitness));
 
-            if let Some(current_best) = &best {
-                if candidate.fitness > current_best.fitness {
-                    best = Some(candidate);
+        let mut source = None;
+        let mut survivors = Vec::new();
+        for candidate in candidates {
+            if let Ok(snapshot) = self.blockchain.load_snapshot(&candidate.checkpoint_id) {
+                if !snapshot.population.is_empty() {
+                    survivors = snapshot.population;
+                    source = Some(candidate);
+                    break;
                 }
+            }
+        }
+        if survivors.is_empty() {
+            survivors = self.hall_of_fame.entries().iter().map(|e| e.organism.clone()).collect();
+        }
+
+        let genome_len = survivors
+            .first()
+            .or(self.population.first())
+            .map(|o| o.dna.genes.len());
+        let Some(genome_len) = genome_len else { return false };
+
+        let policy = &self.config.rebirth;
+        let size = self.population.len().max(survivors.len()).max(policy.min_population);
+        let immigrants = if survivors.is_empty() {
+            size
+        } else {
+            ((size as f32 * policy.immigrant_fraction.clamp(0.0, 1.0)).round() as usize).min(size)
+        };
+
+        self.score(&mut survivors);
+        self.evaluations += survivors.len() as u64;
+        survivors.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+        survivors.truncate(size - immigrants);
+        let restored = survivors.len();
+
+        for _ in 0..immigrants {
+            let id = OrganismId(self.rng.gen());
+            let dna = Dna::new_random(genome_len, &mut self.rng);
+            survivors.push(Organism { birth_generation: self.generation, ..Organism::new(id, dna) });
+        }
+        self.score(&mut survivors[restored..]);
+        self.evaluations += immigrants as u64;
+
+        self.population = survivors;
+        self.rank_population();
+        self.record_population();
+        // The optimizer restarts from the reborn population.
+        self.optimizer = None;
+        self.hall_of_fame.update(&self.population, self.generation);
+        self.last_rebirth = Some(self.generation);
+
+        let event = RebirthEvent {
+            generation: self.generation,
+            cause,
+            checkpoint: source,
+            restored,
+            immigrants,
+        };
+        match self.history.last_mut() {
+            Some(point) if point.generation == self.generation => point.rebirth = Some(event),
+            _ => self.history.push(HistoryPoint {
+                generation: self.generation,
+                best_fitness: self.best_fitness(),
+                species_count: 0,
+                species_sizes: Vec::new(),
+                mutation: None,
+                stats: self.stats(),
+                rebirth: Some(event),
+                hypervolume: self.hypervolume(),
+            }),
+        }
+        true
+    }
+
+    /// Push `base * scale` to the mutator (no-op if it exposes no parameters).
+    fn apply_mutation_scale(&mut self) {
+        if let Some(base) = &self.mutation_base {
+            let params = MutationSchedule::apply(base, self.mutation_scale);
+            self.quantum.set_parameters(&params);
+        }
+    }
+
+    /// Generations since the best fitness in the history last improved.
+    fn stagnant_generations(&self) -> u64 {
+        let mut best = f32::NEG_INFINITY;
+        let mut since = 0;
+        for point in &self.history {
+            if point.best_fitness > best {
+                best = point.best_fitness;
+                since = 0;
             } else {
-                best = Some(candidate);
+                since += 1;
             }
         }
+        since
+    }
 
-        best.expect("tournament selection must yield at least one organism")
+    /// Cluster the current population into species at `config.species_threshold`.
+    pub fn species(&self) -> Vec<speciation::Species> {
+        speciation::speciate(&self.population, self.config.species_threshold)
     }
 
-    /// One-point crossover.
-    fn crossover(&self, a: &Dna, b: &Dna, rng: &mut impl rand::Rng) -> Dna {
-        let len = a.genes.len().min(b.genes.len());
-        if len == 0 {
-            return Dna { genes: Vec::new() };
+    /// Whether the history point of `generation` reports species: always
+    /// with niching, otherwise every `config.species_interval` generations.
+    fn species_due(&self, generation: u64) -> bool {
+        let interval = self.config.species_interval;
+        self.config.niching != Niching::None || (interval > 0 && generation.is_multiple_of(interval))
+    }
+
+    /// Append the current generation to the history. `species_sizes` are
+    /// taken as given when a brood already computed them.
+    fn record_history(&mut self, mutation: Option<MutationParams>, species_sizes: Option<Vec<usize>>) {
+        let species_sizes = match species_sizes {
+            Some(sizes) => sizes,
+            None if self.species_due(self.generation) => {
+                self.species().iter().map(|s| s.members.len()).collect()
+            }
+            None => Vec::new(),
+        };
+        self.push_history(HistoryPoint {
+            generation: self.generation,
+            best_fitness: self.best_fitness(),
+            species_count: species_sizes.len(),
+            species_sizes,
+            mutation: self.mean_strategy().or(mutation),
+            stats: self.stats(),
+            rebirth: None,
+            hypervolume: self.hypervolume(),
+        });
+    }
+
+    fn push_history(&mut self, point: HistoryPoint) {
+        self.history.push(point);
+        if self.history.len() > Self::HISTORY_CAPACITY {
+            let excess = self.history.len() - Self::HISTORY_CAPACITY;
+            self.history.drain(..excess);
         }
+    }
 
-        let point = rng.gen_range(1..len);
-        let mut genes = Vec::with_capacity(len);
-        genes.extend_from_slice(&a.genes[..point]);
-        genes.extend_from_slice(&b.genes[point..len]);
+    /// Add organisms the genealogy has not seen yet (founders, restored or
+    /// injected organisms) without mutation records.
+    fn record_population(&mut self) {
+        for organism in &self.population {
+            self.genealogy.record(organism, Vec::new());
+        }
+    }
 
-        Dna { genes }
+    /// Mean self-adaptive strategy over genomes that carry one.
+    fn mean_strategy(&self) -> Option<MutationParams> {
+        let strategies: Vec<&MutationParams> =
+            self.population.iter().filter_map(|o| o.dna.strategy.as_ref()).collect();
+        if strategies.is_empty() {
+            return None;
+        }
+        let n = strategies.len() as f32;
+        Some(MutationParams {
+            mutation_rate: strategies.iter().map(|s| s.mutation_rate).sum::<f32>() / n,
+            sigma: strategies.iter().map(|s| s.sigma).sum::<f32>() / n,
+        })
+    }
+
+    /// Clones of the top `config.elitism` organisms (capped at `limit`).
+    fn elites(&self, limit: usize) -> Vec<Organism> {
+        let count = self.config.elitism.min(limit).min(self.population.len());
+        if count == 0 {
+            return Vec::with_capacity(limit);
+        }
+
+        let mut ranked: Vec<&Organism> = self.population.iter().collect();
+        ranked.sort_by(|a, b| b.fitness.total_cmp(&a.fitness));
+
+        let mut elites = Vec::with_capacity(limit);
+        elites.extend(ranked.into_iter().take(count).cloned());
+        elites
+    }
+
+    /// Restore engine state from L1 blockchain.
+    ///
+    /// Full snapshots bring back the generation counter, RNG position, mutator
+    /// parameters, evolution config and fitness history, so the run continues
+    /// exactly where it left off. Legacy population-only snapshots restore the
+    /// population and leave everything else untouched.
+    pub fn recover_from_blockchain(
+        &mut self,
+        checkpoint_id: &str,
+    ) -> Result<(), PhoenixError<B::Error>> {
+        let snapshot = self.load_checkpoint(checkpoint_id)?;
+        if snapshot.population.is_empty() {
+            return Err(PhoenixError::Engine(format!(
+                "empty snapshot for checkpoint_id={checkpoint_id}"
+            )));
+        }
+        self.restore(snapshot);
+        Ok(())
+    }
+
+    /// Read a checkpoint from this engine's L1 storage without applying it,
+    /// e.g. to [`fork_from`](Self::fork_from) it into another engine.
+    pub fn load_checkpoint(&self, checkpoint_id: &str) -> Result<EngineSnapshot, PhoenixError<B::Error>> {
+        self.blockchain.load_snapshot(checkpoint_id).map_err(PhoenixError::Blockchain)
+    }
+
+    /// Branch a new timeline: continue this engine from `snapshot`, a
+    /// checkpoint of another engine described by `origin`.
+    ///
+    /// The fork takes over the parent's population, history, RNG position
+    /// and parameters at the checkpoint, then runs independently: override
+    /// `config` or reseed afterwards to run a different experiment. The
+    /// parent's automatic rebirth checkpoints stay behind in its storage,
+    /// and its hall of fame is re-scored with this engine's fitness.
+    pub fn fork_from(
+        &mut self,
+        snapshot: EngineSnapshot,
+        origin: ForkOrigin,
+    ) -> Result<(), PhoenixError<B::Error>> {
+        if snapshot.population.is_empty() {
+            return Err(PhoenixError::Engine(format!(
+                "empty snapshot for checkpoint_id={}",
+                origin.checkpoint_id
+            )));
+        }
+        self.restore(snapshot);
+        self.rebirth_checkpoints.clear();
+        self.origin = Some(origin);
+        // The parent's champions were scored by the parent's fitness.
+        if self.is_multi_objective() {
+            self.hall_of_fame = HallOfFame::new(self.hall_of_fame.capacity);
+        } else {
+            let fitness = &self.fitness;
+            self.hall_of_fame.rescore(|genes| fitness.evaluate(genes));
+        }
+        self.hall_of_fame.update(&self.population, self.generation);
+        Ok(())
+    }
+
+    fn restore(&mut self, snapshot: EngineSnapshot) {
+        let mut restored_evaluations = None;
+        if let (Some(seed), Some(state)) = (snapshot.seed, snapshot.state) {
+            self.seed = seed;
+            self.rng = ChaCha8Rng::seed_from_u64(seed);
+            self.rng.set_word_pos(state.rng_word_pos);
+            self.generation = state.generation;
+            if state.mutation.is_some() {
+                self.mutation_base = state.mutation;
+            }
+            self.mutation_scale = state.mutation_scale;
+            self.apply_mutation_scale();
+            self.config = state.config;
+            self.history = state.history;
+            self.hall_of_fame = state.hall_of_fame;
+            self.rebirth_checkpoints = state.rebirth_checkpoints;
+            self.last_rebirth = state.last_rebirth;
+            self.optimizer = state.optimizer;
+            self.origin = state.origin;
+            restored_evaluations = Some(state.evaluations);
+        }
+
+        self.population = snapshot.population;
+        self.checkpoint_tag = rand::random();
+        self.epoch += 1;
+        self.evaluate_population();
+        // Re-scoring a restored population is not part of the resumed run.
+        if let Some(evaluations) = restored_evaluations {
+            self.evaluations = evaluations;
+        }
+        self.record_population();
+    }
+
+    /// Capture the complete engine state.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            seed: Some(self.seed),
+            population: self.population.clone(),
+            state: Some(EngineState {
+                generation: self.generation,
+                rng_word_pos: self.rng.get_word_pos(),
+                mutation: self.mutation_base.clone(),
+                mutation_scale: self.mutation_scale,
+                config: self.config.clone(),
+                history: self.history.clone(),
+                evaluations: self.evaluations,
+                hall_of_fame: self.hall_of_fame.clone(),
+                rebirth_checkpoints: self.rebirth_checkpoints.clone(),
+                last_rebirth: self.last_rebirth,
+                optimizer: self.optimizer.clone(),
+                origin: self.origin.clone(),
+            }),
+        }
+    }
+
+    /// Persist the complete engine state to blockchain.
+    pub fn checkpoint_to_blockchain(
+        &mut self,
+        checkpoint_id: &str,
+    ) -> Result<(), PhoenixError<B::Error>> {
+        let snapshot = self.snapshot();
+        self.blockchain
+            .store_snapshot(checkpoint_id, &snapshot)
+            .map_err(PhoenixError::Blockchain)
     }
 }
 
@@ -245,7 +1261,7 @@ mod tests {
     struct DummyQuantumMutator;
 
     impl QuantumMutator for DummyQuantumMutator {
-        fn quantum_mutate(&self, dna: &mut Dna) {
+        fn quantum_mutate<R: Rng + ?Sized>(&self, dna: &mut Dna, _rng: &mut R) {
             // Simple, deterministic "quantum-like" mutation for testing:
             for g in &mut dna.genes {
                 *g = (*g + 0.5).fract();
@@ -255,7 +1271,7 @@ mod tests {
 
     #[derive(Default)]
     struct InMemoryBlockchain {
-        pub store: std::collections::HashMap<String, Vec<Organism>>,
+        pub store: std::collections::HashMap<String, EngineSnapshot>,
     }
 
     #[derive(Debug)]
@@ -276,20 +1292,25 @@ mod tests {
     impl BlockchainStorage for InMemoryBlockchain {
         type Error = InMemError;
 
-        fn load_population(&self, checkpoint_id: &str) -> Result<Vec<Organism>, Self::Error> {
+        fn load_snapshot(&self, checkpoint_id: &str) -> Result<EngineSnapshot, Self::Error> {
             self.store
                 .get(checkpoint_id)
                 .cloned()
                 .ok_or(InMemError::NotFound)
         }
 
-        fn store_population(
+        fn store_snapshot(
             &mut self,
             checkpoint_id: &str,
-            population: &[Organism],
+            snapshot: &EngineSnapshot,
         ) -> Result<(), Self::Error> {
             self.store
-                .insert(checkpoint_id.to_string(), population.to_vec());
+                .insert(checkpoint_id.to_string(), snapshot.clone());
+            Ok(())
+        }
+
+        fn remove_snapshot(&mut self, checkpoint_id: &str) -> Result<(), Self::Error> {
+            self.store.remove(checkpoint_id);
             Ok(())
         }
     }
@@ -297,19 +1318,24 @@ mod tests {
     #[test]
     fn evolution_and_recovery_pipeline_works() {
         let mut rng = rand::thread_rng();
-        let quantum = DummyQuantumMutator::default();
+        let quantum = DummyQuantumMutator;
         let blockchain = InMemoryBlockchain::default();
 
         // Initial population.
         let population: Vec<Organism> = (0..8)
             .map(|i| Organism {
-                id: OrganismId(i),
-                dna: Dna::new_random(4, &mut rng),
                 fitness: i as f32, // strictly increasing fitness
+                ..Organism::new(OrganismId(i), Dna::new_random(4, &mut rng))
             })
             .collect();
 
-        let mut engine = PhoenixEngine::new("L3_organisms::O4_day_mohk", quantum, blockchain, population);
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            quantum,
+            blockchain,
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        );
 
         // Checkpoint generation 0.
         engine
@@ -317,7 +1343,7 @@ mod tests {
             .expect("checkpoint gen0 must succeed");
 
         // Evolve to next generation.
-        engine.evolve(&mut rng, 8);
+        engine.evolve(8);
         assert_eq!(engine.population_size(), 8);
         assert_eq!(engine.generation, 1);
 
@@ -325,8 +1351,588 @@ mod tests {
         engine
             .recover_from_blockchain("gen0")
             .expect("recovery from gen0 must succeed");
-        assert_eq!(engine.generation, 1, "generation counter is not rewound automatically");
+        assert_eq!(engine.generation, 0, "generation counter is restored from the checkpoint");
         assert_eq!(engine.population_size(), 8);
+        assert!(engine.history.is_empty());
+    }
+
+    #[test]
+    fn recovery_resumes_the_run_exactly() {
+        let mut init = ChaCha8Rng::seed_from_u64(3);
+        let population: Vec<Organism> = (0..12)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(5, &mut init)))
+            .collect();
+
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_config(EvolutionConfig {
+            elitism: 1,
+            ..EvolutionConfig::default()
+        });
+
+        for _ in 0..3 {
+            engine.evolve(12);
+        }
+        engine.checkpoint_to_blockchain("gen3").unwrap();
+
+        for _ in 0..4 {
+            engine.evolve(12);
+        }
+        let expected = serde_json::to_string(&engine.snapshot()).unwrap();
+
+        // Diverge, then rewind.
+        engine.config.elitism = 0;
+        engine.evolve(12);
+        engine.recover_from_blockchain("gen3").unwrap();
+        assert_eq!(engine.generation, 3);
+        assert_eq!(engine.history.len(), 3);
+        assert_eq!(engine.config.elitism, 1);
+
+        for _ in 0..4 {
+            engine.evolve(12);
+        }
+        assert_eq!(serde_json::to_string(&engine.snapshot()).unwrap(), expected);
+    }
+
+    #[test]
+    fn elitism_keeps_the_best_organism() {
+        let mut rng = rand::thread_rng();
+        let population: Vec<Organism> = (0..10)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+            .collect();
+
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_config(EvolutionConfig {
+            selection: SelectionStrategy::Truncation { fraction: 0.5 },
+            elitism: 2,
+            ..EvolutionConfig::default()
+        });
+
+        for _ in 0..5 {
+            let best = engine.best_fitness();
+            engine.evolve(10);
+            assert_eq!(engine.population_size(), 10);
+            assert!(engine.best_fitness() >= best);
+        }
+
+        engine.config.selection = SelectionStrategy::Rank { pressure: 1.5 };
+        engine.evolve(10);
+        assert_eq!(engine.population_size(), 10);
+    }
+
+    #[test]
+    fn hall_of_fame_keeps_champions_lost_without_elitism() {
+        let mut rng = ChaCha8Rng::seed_from_u64(17);
+        let population: Vec<Organism> = (0..10)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_seed(17)
+        .with_config(EvolutionConfig { elitism: 0, ..EvolutionConfig::default() });
+
+        // Without elitism the best can be lost, but not from the hall of fame.
+        let best = engine.hall_of_fame.best().unwrap().organism.clone();
+        for _ in 0..3 {
+            engine.evolve(10);
+        }
+        assert!(engine.hall_of_fame.best().unwrap().organism.fitness >= best.fitness);
+        assert!(engine.hall_of_fame.entries().len() <= HallOfFame::DEFAULT_CAPACITY);
+
+        let champion = engine.hall_of_fame.best().unwrap().organism.id.clone();
+        assert!(engine.reintroduce_champion(&champion));
+        assert!(engine.population.iter().any(|o| o.id == champion));
+        assert!(!engine.reintroduce_champion(&OrganismId(u64::MAX)));
+    }
+
+    #[test]
+    fn seeded_runs_are_bit_identical() {
+        /// `split` breeds on the engine but scores every brood in parallel
+        /// on another thread.
+        fn run(seed: u64, split: bool) -> Vec<(u64, Vec<u32>, u32)> {
+            let mut init = ChaCha8Rng::seed_from_u64(7);
+            let population: Vec<Organism> = (0..16)
+                .map(|i| Organism::new(OrganismId(i), Dna::new_random(6, &mut init)))
+                .collect();
+
+            let mut engine = PhoenixEngine::new(
+                "L3_organisms::O4_day_mohk",
+                DummyQuantumMutator,
+                InMemoryBlockchain::default(),
+                |genes: &[f32]| genes.iter().sum::<f32>(),
+                population,
+            )
+            .with_seed(seed)
+            .with_config(EvolutionConfig {
+                evaluation: ParallelEvaluation { enabled: split, min_batch: 1 },
+                ..EvolutionConfig::default()
+            });
+
+            for _ in 0..10 {
+                if split {
+                    let brood = engine.breed(16).unwrap();
+                    let brood = std::thread::spawn(move || brood.evaluated()).join().unwrap();
+                    assert!(engine.commit(brood));
+                } else {
+                    engine.evolve(16);
+                }
+            }
+            engine
+                .population
+                .iter()
+                .map(|o| (o.id.0, o.dna.genes.iter().map(|g| g.to_bits()).collect(), o.fitness.to_bits()))
+                .collect()
+        }
+
+        assert_eq!(run(42, false), run(42, false));
+        assert_eq!(run(42, false), run(42, true));
+        assert_ne!(run(42, false), run(43, false));
+    }
+
+    #[test]
+    fn stale_broods_are_not_committed() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let population: Vec<Organism> = (0..6)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(3, &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        );
+
+        let first = engine.breed(6).unwrap();
+        let second = engine.breed(6).unwrap();
+        assert!(!first.is_evaluated());
+        assert!(engine.commit(first));
+        assert!(!engine.commit(second));
+        assert_eq!(engine.generation, 1);
+        assert_eq!(engine.evaluations(), 12);
+
+        // Same generation, but the config or fitness changed meanwhile.
+        let brood = engine.breed(6).unwrap();
+        engine.set_config(EvolutionConfig { elitism: 0, ..EvolutionConfig::default() });
+        assert!(!engine.commit(brood));
+        let brood = engine.breed(6).unwrap();
+        engine.set_fitness_evaluator(|genes: &[f32]| -genes.iter().sum::<f32>());
+        assert!(!engine.commit(brood));
+        let brood = engine.breed(6).unwrap();
+        assert!(engine.commit(brood));
+        assert_eq!(engine.generation, 2);
+    }
+
+    #[test]
+    fn niching_modes_evolve_and_report_species() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let population: Vec<Organism> = (0..20)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+            .collect();
+
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_seed(5)
+        .with_config(EvolutionConfig {
+            niching: Niching::FitnessSharing { sigma: 0.3, alpha: 1.0 },
+            ..EvolutionConfig::default()
+        });
+        engine.evolve(20);
+
+        engine.config.niching = Niching::Crowding { factor: 3 };
+        let best = engine.best_fitness();
+        engine.evolve(8);
+        assert_eq!(engine.population_size(), 20, "crowding keeps the population size");
+        assert!(engine.best_fitness() >= best, "crowding never replaces with a weaker child");
+
+        let last = engine.history.last().unwrap();
+        assert_eq!(last.species_count, last.species_sizes.len());
+        assert_eq!(last.species_sizes.iter().sum::<usize>(), 20);
+
+        // Without niching, species are only sampled every `species_interval`.
+        engine.config.niching = Niching::None;
+        engine.config.species_interval = 2;
+        for _ in 0..2 {
+            engine.evolve(20);
+            let last = engine.history.last().unwrap();
+            assert_eq!(last.species_sizes.is_empty(), last.generation % 2 != 0);
+        }
+    }
+
+    #[test]
+    fn mutation_schedule_scales_mutator_and_is_recorded() {
+        #[derive(Default)]
+        struct ParamMutator(Option<MutationParams>);
+
+        impl QuantumMutator for ParamMutator {
+            fn quantum_mutate<R: Rng + ?Sized>(&self, _dna: &mut Dna, _rng: &mut R) {}
+
+            fn parameters(&self) -> Option<MutationParams> {
+                self.0.clone().or(Some(MutationParams { mutation_rate: 0.5, sigma: 0.2 }))
+            }
+
+            fn set_parameters(&mut self, params: &MutationParams) {
+                self.0 = Some(params.clone());
+            }
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let population: Vec<Organism> = (0..6)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(3, &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            ParamMutator::default(),
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_config(EvolutionConfig {
+            mutation_schedule: MutationSchedule::LinearDecay { generations: 4, final_scale: 0.0 },
+            ..EvolutionConfig::default()
+        });
+
+        for _ in 0..5 {
+            engine.evolve(6);
+        }
+        let rates: Vec<f32> = engine
+            .history
+            .iter()
+            .map(|p| p.mutation.as_ref().unwrap().mutation_rate)
+            .collect();
+        assert_eq!(rates, vec![0.5, 0.375, 0.25, 0.125, 0.0]);
+    }
+
+    #[test]
+    fn offspring_are_recorded_in_the_genealogy() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let population: Vec<Organism> = (0..6)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(3, &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_seed(5);
+
+        engine.evolve(6);
+        engine.evolve(6);
+        assert_eq!(engine.genealogy.len(), 18);
+
+        let child = &engine.population[0];
+        assert_eq!(child.birth_generation, 2);
+        assert_eq!(child.parents.len(), 2);
+        let record = engine.genealogy.get(&child.id).unwrap();
+        assert!(record.mutations.contains(&MutationRecord::Crossover));
+        assert!(matches!(record.mutations.last(), Some(MutationRecord::Point { .. })));
+
+        let ancestors = engine.genealogy.ancestors(&child.id, 2);
+        assert!(ancestors.iter().any(|r| r.birth_generation == 0));
+        assert!(!engine.genealogy.descendants(&ancestors[0].id, 1).is_empty());
+    }
+
+    #[test]
+    fn optimizers_switch_at_runtime_and_resume_from_checkpoints() {
+        let mut init = ChaCha8Rng::seed_from_u64(5);
+        let population: Vec<Organism> = (0..10)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut init)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| -genes.iter().map(|g| (g - 0.5) * (g - 0.5)).sum::<f32>(),
+            population,
+        )
+        .with_seed(5);
+
+        let kinds = [
+            OptimizerKind::DifferentialEvolution { weight: 0.5, crossover_rate: 0.9 },
+            OptimizerKind::SimulatedAnnealing { initial_temperature: 0.1, cooling: 0.9 },
+            OptimizerKind::CmaEs { sigma: 0.2 },
+        ];
+        for kind in kinds {
+            engine.config.optimizer = kind.clone();
+            let (generation, evaluations) = (engine.generation, engine.evaluations());
+            engine.evolve(10);
+            engine.evolve(10);
+            assert_eq!(engine.optimizer().map(Optimizer::kind), Some(kind));
+            assert_eq!(engine.generation, generation + 2);
+            assert_eq!(engine.evaluations(), evaluations + 20);
+            assert_eq!(engine.population.len(), 10);
+            assert!(engine.population.iter().all(|o| o.fitness.is_finite()));
+        }
+        assert_eq!(engine.history.len(), 6);
+        assert!(engine.best_fitness() > -0.05);
+
+        // CMA-ES state travels with the checkpoint.
+        engine.checkpoint_to_blockchain("cma").unwrap();
+        engine.evolve(10);
+        let expected = serde_json::to_string(&engine.snapshot()).unwrap();
+        engine.config.optimizer = OptimizerKind::Genetic;
+        engine.evolve(10);
+        engine.recover_from_blockchain("cma").unwrap();
+        engine.evolve(10);
+        assert_eq!(serde_json::to_string(&engine.snapshot()).unwrap(), expected);
+    }
+
+    #[test]
+    fn forks_branch_independent_timelines_from_a_checkpoint() {
+        let mut init = ChaCha8Rng::seed_from_u64(13);
+        let population: Vec<Organism> = (0..8)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut init)))
+            .collect();
+        let fitness = |genes: &[f32]| genes.iter().sum::<f32>();
+        let genes = |population: &[Organism]| -> Vec<Vec<f32>> {
+            population.iter().map(|o| o.dna.genes.clone()).collect()
+        };
+        let mut parent = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            fitness,
+            population.clone(),
+        )
+        .with_seed(13);
+        for _ in 0..3 {
+            parent.evolve(8);
+        }
+        parent.checkpoint_to_blockchain("gen3").unwrap();
+        let snapshot = parent.load_checkpoint("gen3").unwrap();
+
+        // Forking with unchanged parameters replays the parent exactly.
+        let fork = |snapshot: EngineSnapshot| {
+            let mut engine = PhoenixEngine::new(
+                "L3_organisms::O4_day_mohk",
+                DummyQuantumMutator,
+                InMemoryBlockchain::default(),
+                fitness,
+                population.clone(),
+            );
+            let origin = ForkOrigin { parent: "main".into(), checkpoint_id: "gen3".into(), generation: 3 };
+            engine.fork_from(snapshot, origin).unwrap();
+            engine
+        };
+        let mut replay = fork(snapshot.clone());
+        assert_eq!(replay.generation, 3);
+        assert_eq!(replay.history, parent.history);
+        assert!(replay.rebirth_checkpoints().is_empty());
+        parent.evolve(8);
+        replay.evolve(8);
+        assert_eq!(genes(&replay.population), genes(&parent.population));
+
+        // A fork with other parameters diverges and keeps its lineage
+        // through its own checkpoints.
+        let mut branch = fork(snapshot);
+        branch.config.elitism = 0;
+        branch.config.crossover_rate = 0.0;
+        branch.evolve(8);
+        assert_eq!(branch.history[..3], parent.history[..3]);
+        assert_ne!(genes(&branch.population), genes(&parent.population));
+        branch.checkpoint_to_blockchain("b").unwrap();
+        branch.evolve(8);
+        branch.recover_from_blockchain("b").unwrap();
+        assert_eq!(branch.origin().map(|o| o.checkpoint_id.as_str()), Some("gen3"));
+        assert!(parent.origin().is_none());
+        assert!(parent.load_checkpoint("b").is_err());
+
+        // A fork with another fitness function re-scores the parent's champions.
+        let negated = |genes: &[f32]| -genes.iter().sum::<f32>();
+        let mut other = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            negated,
+            Vec::new(),
+        );
+        let origin = ForkOrigin { parent: "main".into(), checkpoint_id: "gen3".into(), generation: 3 };
+        other.fork_from(parent.load_checkpoint("gen3").unwrap(), origin).unwrap();
+        assert!(!other.hall_of_fame.entries().is_empty());
+        for entry in other.hall_of_fame.entries() {
+            assert_eq!(entry.organism.fitness, negated(&entry.organism.dna.genes));
+        }
+        assert!(other.hall_of_fame.best().unwrap().organism.fitness >= other.best_fitness());
+    }
+
+    #[test]
+    fn collapse_triggers_rebirth_from_an_auto_checkpoint() {
+        let mut rng = ChaCha8Rng::seed_from_u64(9);
+        let population: Vec<Organism> = (0..8)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+            .collect();
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_seed(9)
+        .with_config(EvolutionConfig {
+            rebirth: RebirthPolicy {
+                enabled: true,
+                min_diversity: None,
+                checkpoint_interval: 2,
+                keep_checkpoints: 2,
+                immigrant_fraction: 0.25,
+                ..RebirthPolicy::default()
+            },
+            ..EvolutionConfig::default()
+        });
+
+        for _ in 0..6 {
+            engine.evolve(8);
+        }
+        let kept: Vec<u64> = engine.rebirth_checkpoints().iter().map(|c| c.generation).collect();
+        assert_eq!(kept, vec![4, 6]);
+        // Evicted checkpoints are removed from storage too.
+        let mut stored: Vec<&String> = engine.blockchain.store.keys().collect();
+        stored.sort();
+        let listed: Vec<&String> = engine.rebirth_checkpoints().iter().map(|c| &c.checkpoint_id).collect();
+        assert_eq!(stored, listed);
+        assert_eq!(engine.rebirths().count(), 0);
+
+        // Extinction: nothing left to breed from.
+        engine.population.clear();
+        engine.evolve(8);
+        assert_eq!(engine.population_size(), 8);
+        let event = engine.rebirths().last().unwrap().clone();
+        assert_eq!(event.cause, CollapseCause::Extinction { survivors: 0 });
+        assert_eq!((event.restored, event.immigrants), (6, 2));
+        assert!(kept.contains(&event.checkpoint.unwrap().generation));
+        assert_eq!(event.generation, 6);
+
+        // The cooldown keeps a fresh rebirth from retriggering at once.
+        engine.population.truncate(1);
+        assert!(!engine.rebirth_if_collapsed());
+    }
+
+    #[test]
+    fn packed_populations_evolve_through_the_engine() {
+        use crate::layers::l3_organisms::o4_day_mohk::packed::GeneEncoding;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        let mut packed = PackedPopulation::random(500, 6, GeneEncoding::U8, &mut rng);
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            Vec::new(),
+        )
+        .with_seed(4)
+        .with_config(EvolutionConfig { elitism: 2, ..EvolutionConfig::default() });
+        packed.evaluate_from(0, &|genes: &[f32]| genes.iter().sum::<f32>(), ParallelEvaluation::default());
+
+        let mut best = packed.stats().best;
+        for _ in 0..5 {
+            engine.evolve_packed(&mut packed).unwrap();
+            assert_eq!(packed.len(), 500);
+            assert!(packed.stats().best >= best);
+            best = packed.stats().best;
+        }
+        assert_eq!(engine.generation, 5);
+        assert_eq!(engine.evaluations(), 5 * 498);
+        assert_eq!(engine.history.last().unwrap().best_fitness, best);
+        assert_eq!(engine.hall_of_fame.best().unwrap().organism.fitness, best);
+        assert!(engine.population.is_empty());
+
+        engine.config.crossover = Crossover::Aligned(Default::default());
+        assert!(engine.evolve_packed(&mut packed).is_err());
+        assert_eq!(engine.generation, 5);
+    }
+
+    #[test]
+    fn multi_objective_mode_tracks_the_pareto_front() {
+        use crate::layers::l0_quantum::L0QuantumMutator;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(6);
+        let population: Vec<Organism> = (0..40)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(4, &mut rng)))
+            .collect();
+        // Speed against strength: the front is g[0] in [0, 1] with g[1..] = 0.
+        let objectives = |genes: &[f32]| {
+            let drag = genes[1..].iter().sum::<f32>() / 3.0;
+            vec![genes[0] - drag, 1.0 - genes[0] * genes[0] - drag]
+        };
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            L0QuantumMutator::default(),
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.iter().sum::<f32>(),
+            population,
+        )
+        .with_seed(6)
+        .with_config(EvolutionConfig { hypervolume_reference: vec![-1.0, -1.0], ..EvolutionConfig::default() })
+        .with_objectives(objectives);
+        assert!(engine.is_multi_objective());
+        let initial = engine.hypervolume().unwrap();
+
+        for _ in 0..30 {
+            engine.evolve(40);
+        }
+        assert_eq!(engine.population_size(), 40);
+        assert!(engine.population.iter().all(|o| o.objectives.len() == 2));
+        let front = engine.pareto_front();
+        assert!(!front.is_empty());
+        assert!(front.iter().all(|o| o.fitness > -1.0 && o.fitness <= 0.0));
+        for a in &front {
+            assert!(engine.population.iter().all(|b| !nsga2::dominates(&b.objectives, &a.objectives)));
+        }
+
+        let point = engine.history.last().unwrap();
+        assert_eq!(point.hypervolume, engine.hypervolume());
+        assert!(point.hypervolume.unwrap() > initial);
+    }
+
+    #[test]
+    fn engine_scores_offspring_itself() {
+        let mut rng = rand::thread_rng();
+        let population: Vec<Organism> = (0..6)
+            .map(|i| Organism {
+                fitness: -1.0,
+                ..Organism::new(OrganismId(i), Dna::new_random(5, &mut rng))
+            })
+            .collect();
+
+        let mut engine = PhoenixEngine::new(
+            "L3_organisms::O4_day_mohk",
+            DummyQuantumMutator,
+            InMemoryBlockchain::default(),
+            |genes: &[f32]| genes.len() as f32,
+            population,
+        );
+        assert!(engine.population.iter().all(|o| o.fitness == 5.0));
+
+        engine.evolve(6);
+        assert!(engine.population.iter().all(|o| o.fitness == 5.0));
+
+        engine.set_fitness_evaluator(|genes: &[f32]| -(genes.len() as f32));
+        assert_eq!(engine.best_fitness(), -5.0);
     }
 }
 
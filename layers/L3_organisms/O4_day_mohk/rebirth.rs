@@ -0,0 +1,131 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Automatic Phoenix rebirth.
+//!
+//! With a [`RebirthPolicy`] enabled, the engine checkpoints itself to L1 at
+//! a fixed interval and watches every generation for a collapse: a sharp
+//! drop of the best fitness, diversity falling to (near) zero, or an
+//! extinction that leaves too few organisms. On collapse it rises again
+//! from the fittest recent checkpoint, topped up with random immigrants,
+//! and records a [`RebirthEvent`] in the history.
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::HistoryPoint;
+
+/// When and how the engine reseeds itself. Disabled by default.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RebirthPolicy {
+    pub enabled: bool,
+    /// Collapse when the best fitness falls by more than this fraction of
+    /// its peak over the last `window` generations.
+    pub fitness_drop: Option<f32>,
+    pub window: usize,
+    /// Collapse when population diversity is at or below this value.
+    pub min_diversity: Option<f32>,
+    /// Collapse when fewer organisms than this survive.
+    pub min_population: usize,
+    /// Share of the reborn population made of fresh random organisms.
+    pub immigrant_fraction: f32,
+    /// Generations between automatic checkpoints.
+    pub checkpoint_interval: u64,
+    /// Automatic checkpoints kept as rebirth candidates; older ones are
+    /// removed from storage.
+    pub keep_checkpoints: usize,
+    /// Minimum generations between two rebirths.
+    pub cooldown: u64,
+}
+
+impl Default for RebirthPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fitness_drop: Some(0.5),
+            window: 10,
+            min_diversity: Some(0.0),
+            min_population: 2,
+            immigrant_fraction: 0.25,
+            checkpoint_interval: 10,
+            keep_checkpoints: 5,
+            cooldown: 10,
+        }
+    }
+}
+
+/// What triggered a rebirth.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum CollapseCause {
+    FitnessDrop { peak: f32, best: f32 },
+    DiversityLoss { diversity: f32 },
+    Extinction { survivors: usize },
+}
+
+/// An automatic checkpoint the engine may be reborn from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RebirthCheckpoint {
+    pub checkpoint_id: String,
+    pub generation: u64,
+    pub best_fitness: f32,
+}
+
+/// One rebirth, as recorded in the history.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RebirthEvent {
+    pub generation: u64,
+    pub cause: CollapseCause,
+    /// Checkpoint the survivors came from (`None` if none was usable and the
+    /// hall of fame was used instead).
+    pub checkpoint: Option<RebirthCheckpoint>,
+    /// Organisms restored from the checkpoint or hall of fame.
+    pub restored: usize,
+    /// Fresh random organisms added.
+    pub immigrants: usize,
+}
+
+impl RebirthPolicy {
+    /// Check the latest generation for a collapse. `history` should end with
+    /// the generation just recorded (extinction is detected without it);
+    /// `since` is the generation of the last rebirth (earlier points are
+    /// ignored, so a rebirth does not retrigger on the peak it recovered
+    /// from).
+    pub fn detect(&self, history: &[HistoryPoint], population: usize, since: Option<u64>) -> Option<CollapseCause> {
+        if !self.enabled {
+            return None;
+        }
+        let generation = history.last().map_or(0, |p| p.generation);
+        if let Some(last) = since {
+            if generation < last + self.cooldown {
+                return None;
+            }
+        }
+
+        if population < self.min_population {
+            return Some(CollapseCause::Extinction { survivors: population });
+        }
+
+        let latest = history.last()?;
+        if let Some(min) = self.min_diversity {
+            if population > 1 && latest.stats.diversity <= min {
+                return Some(CollapseCause::DiversityLoss { diversity: latest.stats.diversity });
+            }
+        }
+
+        if let Some(drop) = self.fitness_drop {
+            let earlier = &history[..history.len() - 1];
+            let peak = earlier
+                .iter()
+                .rev()
+                .take(self.window)
+                .filter(|p| since.is_none_or(|g| p.generation > g))
+                .map(|p| p.best_fitness)
+                .fold(None, |acc: Option<f32>, f| Some(acc.map_or(f, |a| a.max(f))));
+            if let Some(peak) = peak {
+                if latest.best_fitness < peak - drop * peak.abs() {
+                    return Some(CollapseCause::FitnessDrop { peak, best: latest.best_fitness });
+                }
+            }
+        }
+        None
+    }
+}
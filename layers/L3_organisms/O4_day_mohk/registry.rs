@@ -0,0 +1,339 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Registry of private engines (multi-tenancy).
+//!
+//! Every tenant (user) owns a set of engines keyed by experiment ID, each
+//! with its own population, parameters, fitness program and L1 storage.
+//! The registry enforces a [`TenantQuota`] on the number of engines, their
+//! population size and the generations evolved per rolling hour, and bounds
+//! the cost of the evolution parameters tenants choose. The shared
+//! world engine is not part of the registry; it stays a single instance
+//! that every user sees.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::crossover::{BlendCrossover, Crossover};
+use super::fitness_dsl::FitnessProgram;
+use super::optimizers::OptimizerKind;
+use super::phoenix_engine::{EvolutionConfig, PhoenixEngine};
+use super::speciation::Niching;
+
+/// Seconds in the rolling window of `TenantQuota::max_generations_per_hour`.
+const QUOTA_WINDOW_SECS: u64 = 3_600;
+
+/// Limits applied to every tenant.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TenantQuota {
+    pub max_engines: usize,
+    pub max_population: usize,
+    /// Generations a tenant may evolve, summed over all its engines, in any
+    /// hour.
+    pub max_generations_per_hour: u64,
+    /// Shortest interval between automatic rebirth checkpoints.
+    pub min_checkpoint_interval: u64,
+    /// Automatic checkpoints an engine may keep.
+    pub max_checkpoints: usize,
+    /// Longest genome CMA-ES may run on (its cost grows cubically with it).
+    pub max_cma_es_genes: usize,
+    /// Smallest batch that may be scored on the shared thread pool.
+    pub min_parallel_batch: usize,
+}
+
+impl Default for TenantQuota {
+    fn default() -> Self {
+        Self {
+            max_engines: 8,
+            max_population: 1_000,
+            max_generations_per_hour: 1_000,
+            min_checkpoint_interval: 10,
+            max_checkpoints: 5,
+            max_cma_es_genes: 64,
+            min_parallel_batch: 256,
+        }
+    }
+}
+
+impl TenantQuota {
+    /// Check a tenant's evolution parameters for an engine whose genomes
+    /// have up to `genes` genes. Length-changing mutations must stay within
+    /// `max_genes`, the longest genome fitness programs are validated for.
+    pub fn check_config(&self, config: &EvolutionConfig, genes: usize, max_genes: usize) -> Result<(), String> {
+        let mut longest = genes;
+        if config.structural.is_enabled() {
+            if config.structural.max_len > max_genes {
+                return Err(format!("structural.max_len must be at most {max_genes}"));
+            }
+            longest = longest.max(config.structural.max_len);
+        }
+        let rebirth = &config.rebirth;
+        if rebirth.enabled {
+            // A rebirth grows the population to at least `min_population`.
+            if rebirth.min_population > self.max_population {
+                return Err(format!("rebirth.min_population must be at most {}", self.max_population));
+            }
+            // 0 never checkpoints.
+            if rebirth.checkpoint_interval > 0 && rebirth.checkpoint_interval < self.min_checkpoint_interval {
+                let min = self.min_checkpoint_interval;
+                return Err(format!("rebirth.checkpoint_interval must be at least {min}"));
+            }
+            if rebirth.keep_checkpoints > self.max_checkpoints {
+                return Err(format!("rebirth.keep_checkpoints must be at most {}", self.max_checkpoints));
+            }
+        }
+        if let Niching::Crowding { factor } = config.niching {
+            if factor > self.max_population {
+                return Err(format!("niching.factor must be at most {}", self.max_population));
+            }
+        }
+        if let Crossover::Blend(BlendCrossover { alpha }) = config.crossover {
+            if !(0.0..=BlendCrossover::MAX_ALPHA).contains(&alpha) {
+                return Err(format!("crossover.alpha must be within 0..={}", BlendCrossover::MAX_ALPHA));
+            }
+        }
+        if matches!(config.optimizer, OptimizerKind::CmaEs { .. }) && longest > self.max_cma_es_genes {
+            return Err(format!("CMA-ES is limited to genomes of {} genes", self.max_cma_es_genes));
+        }
+        if config.evaluation.enabled && config.evaluation.min_batch < self.min_parallel_batch {
+            return Err(format!("evaluation.min_batch must be at least {}", self.min_parallel_batch));
+        }
+        Ok(())
+    }
+}
+
+/// A tenant's consumption of its quota.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    pub engines: usize,
+    pub generations_last_hour: u64,
+    pub quota: TenantQuota,
+}
+
+/// One private engine.
+pub struct RegisteredEngine<Q, B> {
+    pub program: FitnessProgram,
+    pub engine: Arc<Mutex<PhoenixEngine<Q, B>>>,
+    /// Unix seconds.
+    pub created_at: u64,
+}
+
+struct Tenant<Q, B> {
+    engines: BTreeMap<String, RegisteredEngine<Q, B>>,
+    /// `(unix seconds, generations)` granted within the quota window.
+    usage: VecDeque<(u64, u64)>,
+}
+
+impl<Q, B> Default for Tenant<Q, B> {
+    fn default() -> Self {
+        Self { engines: BTreeMap::new(), usage: VecDeque::new() }
+    }
+}
+
+impl<Q, B> Tenant<Q, B> {
+    fn generations_since(&mut self, now: u64) -> u64 {
+        while self.usage.front().is_some_and(|&(at, _)| at + QUOTA_WINDOW_SECS <= now) {
+            self.usage.pop_front();
+        }
+        self.usage.iter().map(|&(_, generations)| generations).sum()
+    }
+}
+
+/// Private engines of every tenant, keyed by tenant and engine ID.
+pub struct EngineRegistry<Q, B> {
+    pub quota: TenantQuota,
+    tenants: HashMap<String, Tenant<Q, B>>,
+}
+
+/// Whether `id` can name an engine: 1–64 ASCII letters, digits, `-` or `_`.
+pub fn valid_engine_id(id: &str) -> bool {
+    (1..=64).contains(&id.len()) && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+impl<Q, B> EngineRegistry<Q, B> {
+    pub fn new(quota: TenantQuota) -> Self {
+        Self { quota, tenants: HashMap::new() }
+    }
+
+    /// Check that a tenant may create an engine `id` of `population` organisms.
+    pub fn check_new(&self, tenant: &str, id: &str, population: usize) -> Result<(), String> {
+        if !valid_engine_id(id) {
+            return Err("engine IDs are 1-64 letters, digits, '-' or '_'".to_string());
+        }
+        if population > self.quota.max_population {
+            return Err(format!("population quota is {} organisms", self.quota.max_population));
+        }
+        let engines = self.tenants.get(tenant).map_or(0, |t| t.engines.len());
+        if self.get(tenant, id).is_some() {
+            return Err(format!("engine {id} already exists"));
+        }
+        if engines >= self.quota.max_engines {
+            return Err(format!("engine quota is {} engines", self.quota.max_engines));
+        }
+        Ok(())
+    }
+
+    /// Register an engine after [`check_new`](Self::check_new) (checked again).
+    pub fn insert(&mut self, tenant: &str, id: &str, entry: RegisteredEngine<Q, B>) -> Result<(), String> {
+        let population = entry.engine.lock().unwrap().population_size();
+        self.check_new(tenant, id, population)?;
+        self.tenants.entry(tenant.to_string()).or_default().engines.insert(id.to_string(), entry);
+        Ok(())
+    }
+
+    pub fn get(&self, tenant: &str, id: &str) -> Option<&RegisteredEngine<Q, B>> {
+        self.tenants.get(tenant)?.engines.get(id)
+    }
+
+    /// Unregister an engine (its checkpoints stay in L1). The tenant's
+    /// generation usage is kept.
+    pub fn remove(&mut self, tenant: &str, id: &str) -> Option<RegisteredEngine<Q, B>> {
+        self.tenants.get_mut(tenant)?.engines.remove(id)
+    }
+
+    /// A tenant's engines, by ID.
+    pub fn engines(&self, tenant: &str) -> impl Iterator<Item = (&String, &RegisteredEngine<Q, B>)> {
+        self.tenants.get(tenant).into_iter().flat_map(|t| t.engines.iter())
+    }
+
+    /// Grant up to `requested` generations at unix time `now` and charge
+    /// them to the tenant. Errors once the hourly quota is spent.
+    pub fn reserve_generations(&mut self, tenant: &str, requested: u64, now: u64) -> Result<u64, String> {
+        let limit = self.quota.max_generations_per_hour;
+        let entry = self.tenants.entry(tenant.to_string()).or_default();
+        let granted = requested.min(limit.saturating_sub(entry.generations_since(now)));
+        if granted == 0 && requested > 0 {
+            return Err(format!("generation quota of {limit} per hour is spent"));
+        }
+        entry.usage.push_back((now, granted));
+        Ok(granted)
+    }
+
+    /// Give back `generations` of the tenant's latest reservations, e.g.
+    /// when a reserved generation was not committed after all.
+    pub fn refund_generations(&mut self, tenant: &str, generations: u64) {
+        let Some(entry) = self.tenants.get_mut(tenant) else { return };
+        let mut left = generations;
+        while let Some((_, granted)) = entry.usage.back_mut() {
+            let refunded = left.min(*granted);
+            *granted -= refunded;
+            left -= refunded;
+            if *granted > 0 {
+                break;
+            }
+            entry.usage.pop_back();
+        }
+    }
+
+    pub fn usage(&mut self, tenant: &str, now: u64) -> QuotaUsage {
+        let quota = self.quota;
+        match self.tenants.get_mut(tenant) {
+            Some(t) => QuotaUsage { engines: t.engines.len(), generations_last_hour: t.generations_since(now), quota },
+            None => QuotaUsage { engines: 0, generations_last_hour: 0, quota },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l0_quantum::L0QuantumMutator;
+    use crate::layers::l1_chronos::L1ChronosFileStorage;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, Organism, OrganismId};
+
+    fn entry(tenant: &str, id: &str, size: u64) -> RegisteredEngine<L0QuantumMutator, L1ChronosFileStorage> {
+        let population = (0..size).map(|i| Organism::new(OrganismId(i), Dna::from_genes(vec![0.5; 3]))).collect();
+        let storage = L1ChronosFileStorage::new(std::env::temp_dir()).tenant(tenant, id);
+        let program = FitnessProgram::new("sum(g[i])");
+        let fitness = program.compile(&Default::default()).unwrap();
+        let engine = PhoenixEngine::new("test", L0QuantumMutator::default(), storage, fitness, population);
+        RegisteredEngine { program, engine: Arc::new(Mutex::new(engine)), created_at: 0 }
+    }
+
+    #[test]
+    fn tenants_are_isolated_and_held_to_their_quota() {
+        let mut registry =
+            EngineRegistry::new(TenantQuota {
+                max_engines: 2,
+                max_population: 10,
+                max_generations_per_hour: 50,
+                ..TenantQuota::default()
+            });
+
+        registry.insert("ada", "a", entry("ada", "a", 4)).unwrap();
+        registry.insert("ada", "b", entry("ada", "b", 10)).unwrap();
+        registry.insert("bob", "a", entry("bob", "a", 4)).unwrap();
+        assert!(registry.insert("ada", "c", entry("ada", "c", 4)).unwrap_err().contains("engine quota"));
+        assert!(registry.insert("bob", "a", entry("bob", "a", 4)).unwrap_err().contains("exists"));
+        assert!(registry.check_new("bob", "big", 11).unwrap_err().contains("population quota"));
+        assert!(registry.check_new("bob", "../x", 1).is_err());
+        assert!(registry.get("bob", "b").is_none());
+        assert_eq!(registry.engines("ada").count(), 2);
+
+        // Distinct tenants never share an L1 directory.
+        let root = L1ChronosFileStorage::new("root");
+        assert_ne!(root.tenant("a/b", "x").root(), root.tenant("a_b", "x").root());
+
+        assert_eq!(registry.reserve_generations("ada", 30, 1_000), Ok(30));
+        assert_eq!(registry.reserve_generations("ada", 30, 2_000), Ok(20));
+        assert!(registry.reserve_generations("ada", 1, 3_000).is_err());
+        assert_eq!(registry.reserve_generations("bob", 50, 3_000), Ok(50));
+        assert_eq!(registry.usage("ada", 4_600).generations_last_hour, 20);
+        assert_eq!(registry.reserve_generations("ada", 100, 5_600), Ok(50));
+        registry.refund_generations("ada", 10);
+        assert_eq!(registry.usage("ada", 5_600).generations_last_hour, 40);
+
+        registry.remove("ada", "a").unwrap();
+        assert_eq!(registry.usage("ada", 5_600).engines, 1);
+    }
+
+    #[test]
+    fn costly_configs_are_rejected() {
+        let quota = TenantQuota::default();
+        let check = |config: &EvolutionConfig, genes| quota.check_config(config, genes, 256);
+        let mut config = EvolutionConfig::default();
+        assert_eq!(check(&config, 256), Ok(()));
+
+        config.structural.insertion_rate = 0.1;
+        config.structural.max_len = 100_000;
+        assert!(check(&config, 8).unwrap_err().contains("max_len"));
+        config.structural.max_len = 32;
+        config.optimizer = OptimizerKind::CmaEs { sigma: 0.3 };
+        assert_eq!(check(&config, 8), Ok(()));
+        assert!(check(&config, 256).unwrap_err().contains("CMA-ES"));
+        config.structural.max_len = 128;
+        assert!(check(&config, 8).unwrap_err().contains("CMA-ES"));
+
+        let mut config = EvolutionConfig::default();
+        config.rebirth.enabled = true;
+        config.rebirth.checkpoint_interval = 1;
+        assert!(check(&config, 8).unwrap_err().contains("checkpoint_interval"));
+        config.rebirth.checkpoint_interval = 10;
+        config.rebirth.keep_checkpoints = 1_000;
+        assert!(check(&config, 8).unwrap_err().contains("keep_checkpoints"));
+        // Rebirth bounds hold without checkpoints too.
+        config.rebirth.checkpoint_interval = 0;
+        assert!(check(&config, 8).unwrap_err().contains("keep_checkpoints"));
+        config.rebirth.keep_checkpoints = 5;
+        config.rebirth.min_population = 1_000_000_000;
+        assert!(check(&config, 8).unwrap_err().contains("min_population"));
+
+        let mut config = EvolutionConfig::default();
+        config.niching = Niching::Crowding { factor: usize::MAX };
+        assert!(check(&config, 8).unwrap_err().contains("factor"));
+        config.niching = Niching::Crowding { factor: 3 };
+        config.crossover = Crossover::Blend(BlendCrossover { alpha: f32::INFINITY });
+        assert!(check(&config, 8).unwrap_err().contains("alpha"));
+        config.crossover = Crossover::Blend(BlendCrossover { alpha: f32::NAN });
+        assert!(check(&config, 8).is_err());
+        config.crossover = Crossover::Blend(BlendCrossover::default());
+        assert_eq!(check(&config, 8), Ok(()));
+
+        let mut config = EvolutionConfig::default();
+        config.evaluation.min_batch = 1;
+        assert!(check(&config, 8).unwrap_err().contains("min_batch"));
+        config.evaluation.enabled = false;
+        assert_eq!(check(&config, 8), Ok(()));
+    }
+}
@@ -0,0 +1,299 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Run controller with termination criteria.
+//!
+//! `RunController` drives a `PhoenixEngine` generation by generation until
+//! one of its stop conditions fires, and reports which one did. Runs can
+//! also be cancelled from another thread through a [`StopHandle`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{BlockchainStorage, PhoenixEngine, QuantumMutator};
+
+/// When a run should end. Unset criteria never fire; a run with no criteria
+/// at all only ends when it is cancelled.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TerminationCriteria {
+    /// Stop once the best fitness reaches this value.
+    pub target_fitness: Option<f32>,
+    /// Stop after this many generations without a new best fitness.
+    pub max_stagnant_generations: Option<u64>,
+    /// Wall-clock budget in seconds.
+    pub max_seconds: Option<f64>,
+    /// Budget of fitness evaluations spent by this run.
+    pub max_evaluations: Option<u64>,
+    /// Stop when population diversity falls below this value.
+    pub min_diversity: Option<f32>,
+    /// Hard cap on the number of generations of this run.
+    pub max_generations: Option<u64>,
+}
+
+impl TerminationCriteria {
+    /// Whether a budget (generations, evaluations or wall-clock time)
+    /// guarantees the run ends without being cancelled.
+    pub fn is_bounded(&self) -> bool {
+        self.max_generations.is_some() || self.max_evaluations.is_some() || self.max_seconds.is_some()
+    }
+}
+
+/// Why a run ended.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StopReason {
+    TargetFitness { fitness: f32 },
+    Stagnation { generations: u64 },
+    WallClock { seconds: f64 },
+    EvaluationBudget { evaluations: u64 },
+    DiversityCollapse { diversity: f32 },
+    GenerationLimit { generations: u64 },
+    /// A step committed no generation and left nothing to breed from
+    /// (e.g. an extinct population without rebirth).
+    Stalled { generation: u64 },
+    /// Stopped through a [`StopHandle`].
+    Cancelled,
+}
+
+/// Progress of a run, counted from the moment it started.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunProgress {
+    pub generations: u64,
+    pub evaluations: u64,
+    pub best_fitness: f32,
+    pub stagnant_generations: u64,
+    pub elapsed_seconds: f64,
+}
+
+/// Outcome of a finished run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub reason: StopReason,
+    pub progress: RunProgress,
+}
+
+/// Cloneable flag that cancels a running [`RunController`].
+#[derive(Clone, Debug, Default)]
+pub struct StopHandle(Arc<AtomicBool>);
+
+impl StopHandle {
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Drives an engine until a termination criterion is met.
+pub struct RunController {
+    pub criteria: TerminationCriteria,
+    /// Offspring per generation; `None` keeps the population size.
+    pub offspring_count: Option<usize>,
+    stop: StopHandle,
+    started: Instant,
+    start_generation: u64,
+    start_evaluations: u64,
+    best: f32,
+    stagnant: u64,
+}
+
+impl RunController {
+    /// Start a run on `engine` (progress is counted from its current state).
+    pub fn new<Q, B>(criteria: TerminationCriteria, engine: &PhoenixEngine<Q, B>) -> Self {
+        Self {
+            criteria,
+            offspring_count: None,
+            stop: StopHandle::default(),
+            started: Instant::now(),
+            start_generation: engine.generation,
+            start_evaluations: engine.evaluations(),
+            best: engine.best_fitness(),
+            stagnant: 0,
+        }
+    }
+
+    pub fn with_offspring_count(mut self, offspring_count: usize) -> Self {
+        self.offspring_count = Some(offspring_count);
+        self
+    }
+
+    /// Handle that cancels this run from elsewhere.
+    pub fn stop_handle(&self) -> StopHandle {
+        self.stop.clone()
+    }
+
+    /// Progress so far.
+    pub fn progress<Q, B>(&self, engine: &PhoenixEngine<Q, B>) -> RunProgress {
+        RunProgress {
+            generations: engine.generation.saturating_sub(self.start_generation),
+            evaluations: engine.evaluations().saturating_sub(self.start_evaluations),
+            best_fitness: self.best,
+            stagnant_generations: self.stagnant,
+            elapsed_seconds: self.started.elapsed().as_secs_f64(),
+        }
+    }
+
+    /// First criterion that currently holds, if any.
+    pub fn check<Q, B>(&self, engine: &PhoenixEngine<Q, B>) -> Option<StopReason> {
+        let c = &self.criteria;
+        let progress = self.progress(engine);
+
+        if self.stop.is_stopped() {
+            return Some(StopReason::Cancelled);
+        }
+        if let Some(target) = c.target_fitness {
+            if self.best >= target {
+                return Some(StopReason::TargetFitness { fitness: self.best });
+            }
+        }
+        if let Some(limit) = c.max_stagnant_generations {
+            if self.stagnant >= limit {
+                return Some(StopReason::Stagnation { generations: self.stagnant });
+            }
+        }
+        if let Some(limit) = c.max_seconds {
+            if progress.elapsed_seconds >= limit {
+                return Some(StopReason::WallClock { seconds: progress.elapsed_seconds });
+            }
+        }
+        if let Some(limit) = c.max_evaluations {
+            if progress.evaluations >= limit {
+                return Some(StopReason::EvaluationBudget { evaluations: progress.evaluations });
+            }
+        }
+        if let (Some(min), Some(point)) = (c.min_diversity, engine.history.last()) {
+            if progress.generations > 0 && point.stats.diversity < min {
+                return Some(StopReason::DiversityCollapse { diversity: point.stats.diversity });
+            }
+        }
+        if let Some(limit) = c.max_generations {
+            if progress.generations >= limit {
+                return Some(StopReason::GenerationLimit { generations: progress.generations });
+            }
+        }
+        None
+    }
+
+    /// Evolve one generation unless the run is already over; returns the
+    /// stop reason once a criterion holds.
+    pub fn step<Q, B>(&mut self, engine: &mut PhoenixEngine<Q, B>) -> Option<StopReason>
+    where
+        Q: QuantumMutator,
+        B: BlockchainStorage,
+    {
+        if let Some(reason) = self.check(engine) {
+            return Some(reason);
+        }
+
+        let generation = engine.generation;
+        let offspring = self.offspring_count.unwrap_or_else(|| engine.population_size());
+        engine.evolve(offspring);
+        if let Some(reason) = Self::stalled(engine, generation) {
+            return Some(reason);
+        }
+        self.observe(engine)
+    }
+
+    /// The stall reason when no generation was committed since `generation`
+    /// and the population is empty, so further steps cannot advance. A
+    /// rebirth that refilled the population is not a stall.
+    pub fn stalled<Q, B>(engine: &PhoenixEngine<Q, B>, generation: u64) -> Option<StopReason> {
+        (engine.generation == generation && engine.population_size() == 0)
+            .then_some(StopReason::Stalled { generation })
+    }
+
+    /// Account for a generation the caller evolved itself (e.g. through
+    /// `breed`/`commit`); returns the stop reason once a criterion holds.
+    pub fn observe<Q, B>(&mut self, engine: &PhoenixEngine<Q, B>) -> Option<StopReason> {
+        let best = engine.best_fitness();
+        if best > self.best {
+            self.best = best;
+            self.stagnant = 0;
+        } else {
+            self.stagnant += 1;
+        }
+        self.check(engine)
+    }
+
+    /// Evolve until a criterion holds.
+    pub fn run<Q, B>(&mut self, engine: &mut PhoenixEngine<Q, B>) -> RunReport
+    where
+        Q: QuantumMutator,
+        B: BlockchainStorage,
+    {
+        loop {
+            if let Some(reason) = self.step(engine) {
+                return RunReport { reason, progress: self.progress(engine) };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l0_quantum::L0QuantumMutator;
+    use crate::layers::l1_chronos::L1ChronosFileStorage;
+    use crate::layers::l3_organisms::o4_day_mohk::fitness::TargetPeakFitness;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, Organism, OrganismId};
+
+    fn engine() -> PhoenixEngine<L0QuantumMutator, L1ChronosFileStorage> {
+        let population = (0..10)
+            .map(|i| Organism::new(OrganismId(i), Dna::from_genes(vec![0.1 * (i % 5) as f32; 4])))
+            .collect();
+        PhoenixEngine::new(
+            "test",
+            L0QuantumMutator::default(),
+            L1ChronosFileStorage::new(std::env::temp_dir()),
+            TargetPeakFitness::default(),
+            population,
+        )
+        .with_seed(13)
+    }
+
+    #[test]
+    fn stops_on_the_first_criterion_that_holds() {
+        let mut engine = engine();
+        let report = RunController::new(
+            TerminationCriteria { max_generations: Some(3), ..Default::default() },
+            &engine,
+        )
+        .run(&mut engine);
+        assert_eq!(report.reason, StopReason::GenerationLimit { generations: 3 });
+        assert_eq!(report.progress.evaluations, 30);
+
+        let report = RunController::new(
+            TerminationCriteria { max_evaluations: Some(25), max_generations: Some(100), ..Default::default() },
+            &engine,
+        )
+        .run(&mut engine);
+        assert_eq!(report.reason, StopReason::EvaluationBudget { evaluations: 30 });
+
+        let report = RunController::new(
+            TerminationCriteria { target_fitness: Some(0.0), ..Default::default() },
+            &engine,
+        )
+        .run(&mut engine);
+        assert!(matches!(report.reason, StopReason::TargetFitness { .. }));
+        assert_eq!(report.progress.generations, 0);
+
+        assert!(!TerminationCriteria { target_fitness: Some(1.0), ..Default::default() }.is_bounded());
+        let mut controller = RunController::new(TerminationCriteria::default(), &engine);
+        controller.stop_handle().stop();
+        assert_eq!(controller.run(&mut engine).reason, StopReason::Cancelled);
+
+        // Zero offspring empties the population; the run must end, not spin.
+        let report = RunController::new(
+            TerminationCriteria { max_generations: Some(100), ..Default::default() },
+            &engine,
+        )
+        .with_offspring_count(0)
+        .run(&mut engine);
+        assert!(matches!(report.reason, StopReason::Stalled { .. }));
+        assert!(report.progress.generations < 100);
+    }
+}
@@ -0,0 +1,102 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Parent selection strategies for the Phoenix Engine.
+//!
+//! Strategies work on a plain fitness slice (higher is better) and return
+//! indices into it, so the engine can feed them raw or adjusted fitness values.
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// How parents are picked from the current population.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SelectionStrategy {
+    /// Best of `size` uniformly drawn organisms.
+    Tournament { size: usize },
+    /// Fitness-proportionate selection (fitness is shifted to be non-negative).
+    RouletteWheel,
+    /// Linear ranking; `pressure` in `[1.0, 2.0]` is the expected number of
+    /// offspring of the best organism.
+    Rank { pressure: f32 },
+    /// Uniform selection among the top `fraction` of the population.
+    Truncation { fraction: f32 },
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        SelectionStrategy::Tournament { size: 3 }
+    }
+}
+
+impl SelectionStrategy {
+    /// Select `count` parent indices from `fitness`.
+    ///
+    /// Returns an empty vector when `fitness` is empty. Non-finite fitness
+    /// values are treated as the worst possible score.
+    pub fn select<R: Rng + ?Sized>(&self, fitness: &[f32], count: usize, rng: &mut R) -> Vec<usize> {
+        if fitness.is_empty() {
+            return Vec::new();
+        }
+
+        let fitness: Vec<f32> = fitness
+            .iter()
+            .map(|&f| if f.is_finite() { f } else { f32::MIN })
+            .collect();
+
+        match self {
+            SelectionStrategy::Tournament { size } => {
+                let k = (*size).clamp(1, fitness.len());
+                (0..count)
+                    .map(|_| {
+                        (0..k)
+                            .map(|_| rng.gen_range(0..fitness.len()))
+                            .reduce(|best, i| if fitness[i] > fitness[best] { i } else { best })
+                            .expect("tournament size is at least 1")
+                    })
+                    .collect()
+            }
+            SelectionStrategy::RouletteWheel => {
+                let min = fitness.iter().copied().fold(f32::INFINITY, f32::min);
+                let weights: Vec<f64> = fitness.iter().map(|&f| (f - min) as f64).collect();
+                Self::sample_weighted(&weights, count, rng)
+            }
+            SelectionStrategy::Rank { pressure } => {
+                let s = pressure.clamp(1.0, 2.0) as f64;
+                let n = fitness.len();
+                let mut weights = vec![0.0; n];
+                for (rank, idx) in Self::ascending_order(&fitness).into_iter().enumerate() {
+                    weights[idx] = if n > 1 {
+                        (2.0 - s) + 2.0 * (s - 1.0) * rank as f64 / (n - 1) as f64
+                    } else {
+                        1.0
+                    };
+                }
+                Self::sample_weighted(&weights, count, rng)
+            }
+            SelectionStrategy::Truncation { fraction } => {
+                let fraction = if fraction.is_finite() { fraction.clamp(0.0, 1.0) } else { 1.0 };
+                let keep = ((fitness.len() as f32 * fraction).ceil() as usize).clamp(1, fitness.len());
+                let mut order = Self::ascending_order(&fitness);
+                order.reverse();
+                order.truncate(keep);
+                (0..count).map(|_| order[rng.gen_range(0..order.len())]).collect()
+            }
+        }
+    }
+
+    /// Indices sorted from worst to best fitness.
+    fn ascending_order(fitness: &[f32]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..fitness.len()).collect();
+        order.sort_by(|&a, &b| fitness[a].total_cmp(&fitness[b]));
+        order
+    }
+
+    /// Weighted sampling with a uniform fallback when all weights are zero.
+    fn sample_weighted<R: Rng + ?Sized>(weights: &[f64], count: usize, rng: &mut R) -> Vec<usize> {
+        match WeightedIndex::new(weights) {
+            Ok(dist) => (0..count).map(|_| dist.sample(rng)).collect(),
+            Err(_) => (0..count).map(|_| rng.gen_range(0..weights.len())).collect(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Speciation and niching for the Phoenix Engine.
+//!
+//! Provides a genetic distance between genomes, a greedy clustering of the
+//! population into species, and the diversity pressure applied during
+//! evolution (fitness sharing or crowding).
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::{Dna, Organism};
+
+/// Diversity-preservation method used by the engine.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Niching {
+    /// No diversity pressure.
+    #[default]
+    None,
+    /// Goldberg–Richardson fitness sharing applied before selection:
+    /// organisms closer than `sigma` share their fitness with each other.
+    FitnessSharing { sigma: f32, alpha: f32 },
+    /// De Jong crowding: each child replaces the most similar of `factor`
+    /// randomly drawn organisms if it is at least as fit. The population
+    /// size is kept constant; `offspring_count` sets how many children compete.
+    Crowding { factor: usize },
+}
+
+/// A cluster of genetically similar organisms.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Species {
+    pub id: usize,
+    /// Index (into the population) of the organism that founded the species.
+    pub representative: usize,
+    /// Indices of all members, representative included.
+    pub members: Vec<usize>,
+}
+
+/// Root-mean-square gene difference, in \[0, 1\].
+///
+/// Genes present in only one genome count as maximally different, so
+/// genomes of different lengths are comparable.
+pub fn genetic_distance(a: &Dna, b: &Dna) -> f32 {
+    let max_len = a.genes.len().max(b.genes.len());
+    if max_len == 0 {
+        return 0.0;
+    }
+
+    let common = a.genes.len().min(b.genes.len());
+    let shared: f32 = a.genes[..common]
+        .iter()
+        .zip(&b.genes[..common])
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum();
+    let unmatched = (max_len - common) as f32;
+
+    ((shared + unmatched) / max_len as f32).sqrt()
+}
+
+/// Greedily cluster `population` into species.
+///
+/// Organisms are visited from fittest to weakest; each joins the first
+/// species whose representative is within `threshold`, or founds a new one.
+/// Species are returned in founding order (i.e. by best member).
+pub fn speciate(population: &[Organism], threshold: f32) -> Vec<Species> {
+    let mut order: Vec<usize> = (0..population.len()).collect();
+    order.sort_by(|&a, &b| population[b].fitness.total_cmp(&population[a].fitness));
+
+    let mut species: Vec<Species> = Vec::new();
+    for idx in order {
+        let dna = &population[idx].dna;
+        match species
+            .iter_mut()
+            .find(|s| genetic_distance(&population[s.representative].dna, dna) <= threshold)
+        {
+            Some(s) => s.members.push(idx),
+            None => species.push(Species {
+                id: species.len(),
+                representative: idx,
+                members: vec![idx],
+            }),
+        }
+    }
+    species
+}
+
+/// Fitness after sharing: `(f - min) / Σ sh(d)` with
+/// `sh(d) = 1 - (d / sigma)^alpha` for `d < sigma`.
+///
+/// Fitness is shifted to be non-negative first so that sharing always
+/// penalises crowded niches.
+pub fn shared_fitness(population: &[Organism], sigma: f32, alpha: f32) -> Vec<f32> {
+    let min = population
+        .iter()
+        .map(|o| o.fitness)
+        .filter(|f| f.is_finite())
+        .fold(f32::INFINITY, f32::min);
+    if !min.is_finite() || sigma <= 0.0 {
+        return population.iter().map(|o| o.fitness).collect();
+    }
+
+    population
+        .iter()
+        .map(|o| {
+            let niche_count: f32 = population
+                .iter()
+                .map(|other| {
+                    let d = genetic_distance(&o.dna, &other.dna);
+                    if d < sigma { 1.0 - (d / sigma).powf(alpha) } else { 0.0 }
+                })
+                .sum();
+            // `niche_count >= 1` because every organism shares with itself.
+            (o.fitness - min) / niche_count.max(1.0)
+        })
+        .collect()
+}
+
+/// De Jong crowding replacement: each child replaces the most similar of
+/// `factor` randomly drawn members of `population` if it is at least as fit.
+/// `factor` is clamped to `1..=population.len()`.
+pub fn crowding_replace<R: Rng + ?Sized>(
+    population: &mut [Organism],
+    offspring: Vec<Organism>,
+    factor: usize,
+    rng: &mut R,
+) {
+    if population.is_empty() {
+        return;
+    }
+
+    let factor = factor.clamp(1, population.len());
+    for child in offspring {
+        let closest = (0..factor)
+            .map(|_| rng.gen_range(0..population.len()))
+            .min_by(|&a, &b| {
+                genetic_distance(&population[a].dna, &child.dna)
+                    .total_cmp(&genetic_distance(&population[b].dna, &child.dna))
+            })
+            .expect("factor is at least 1");
+        if child.fitness >= population[closest].fitness {
+            population[closest] = child;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::OrganismId;
+
+    fn organism(id: u64, genes: Vec<f32>, fitness: f32) -> Organism {
+        Organism { fitness, ..Organism::new(OrganismId(id), Dna::from_genes(genes)) }
+    }
+
+    #[test]
+    fn clusters_and_shares_crowded_niches() {
+        let population = vec![
+            organism(0, vec![0.1, 0.1], 1.0),
+            organism(1, vec![0.12, 0.1], 1.0),
+            organism(2, vec![0.11, 0.09], 1.0),
+            organism(3, vec![0.9, 0.9], 1.0),
+            organism(4, vec![0.0, 0.5], 0.0),
+        ];
+
+        assert_eq!(genetic_distance(&population[0].dna, &population[0].dna), 0.0);
+        assert_eq!(genetic_distance(&Dna::from_genes(vec![0.5]), &Dna::from_genes(vec![0.5, 0.5])), 0.5_f32.sqrt());
+
+        let species = speciate(&population, 0.1);
+        let sizes: Vec<usize> = species.iter().map(|s| s.members.len()).collect();
+        assert_eq!(sizes, vec![3, 1, 1]);
+
+        // The lone organism keeps its full fitness; the crowded trio is penalised.
+        let shared = shared_fitness(&population, 0.2, 1.0);
+        assert!(shared[3] > shared[0]);
+        assert!((shared[3] - 1.0).abs() < 1e-6);
+
+        // A huge crowding factor is clamped to the population size.
+        let mut crowded = population.clone();
+        let child = organism(5, vec![0.1, 0.1], 2.0);
+        crowding_replace(&mut crowded, vec![child], usize::MAX, &mut rand::thread_rng());
+        assert_eq!(crowded.len(), 5);
+        assert!(crowded.iter().any(|o| o.id == OrganismId(5)));
+    }
+}
@@ -0,0 +1,159 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Population statistics for the Phoenix Engine.
+//!
+//! Summaries are computed once per generation and stored in the history, so
+//! dashboards can plot fitness spread, per-gene drift and loss of diversity
+//! without shipping whole populations around.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::Organism;
+use super::speciation::genetic_distance;
+
+/// Descriptive statistics of one population.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PopulationStats {
+    pub size: usize,
+    pub best: f32,
+    pub mean: f32,
+    pub median: f32,
+    pub worst: f32,
+    pub std_dev: f32,
+    /// Mean of each gene position over the organisms that have it.
+    pub gene_means: Vec<f32>,
+    /// Population variance of each gene position.
+    pub gene_variances: Vec<f32>,
+    /// Mean pairwise genetic distance, in \[0, 1\] (0 = all identical).
+    pub diversity: f32,
+    /// Number of distinct genomes (bit-exact gene vectors).
+    pub unique_genotypes: usize,
+}
+
+/// Diversity is estimated from at most this many evenly spaced organisms,
+/// which keeps the pairwise computation bounded on large populations.
+pub const DIVERSITY_SAMPLE: usize = 128;
+
+/// Compute [`PopulationStats`] for `population`. Non-finite fitness values
+/// are ignored in the fitness moments; an empty population yields zeros.
+pub fn population_stats(population: &[Organism]) -> PopulationStats {
+    if population.is_empty() {
+        return PopulationStats::default();
+    }
+
+    let mut fitness: Vec<f32> = population.iter().map(|o| o.fitness).filter(|f| f.is_finite()).collect();
+    fitness.sort_by(f32::total_cmp);
+    let (best, mean, median, worst, std_dev) = if fitness.is_empty() {
+        (0.0, 0.0, 0.0, 0.0, 0.0)
+    } else {
+        let n = fitness.len();
+        let mean = fitness.iter().sum::<f32>() / n as f32;
+        let median = if n % 2 == 1 {
+            fitness[n / 2]
+        } else {
+            (fitness[n / 2 - 1] + fitness[n / 2]) / 2.0
+        };
+        let variance = fitness.iter().map(|f| (f - mean) * (f - mean)).sum::<f32>() / n as f32;
+        (fitness[n - 1], mean, median, fitness[0], variance.sqrt())
+    };
+
+    let (gene_means, gene_variances) = gene_moments(population);
+
+    PopulationStats {
+        size: population.len(),
+        best,
+        mean,
+        median,
+        worst,
+        std_dev,
+        gene_means,
+        gene_variances,
+        diversity: diversity(population),
+        unique_genotypes: unique_genotypes(population),
+    }
+}
+
+/// Per-position mean and variance; genomes may differ in length.
+fn gene_moments(population: &[Organism]) -> (Vec<f32>, Vec<f32>) {
+    let len = population.iter().map(|o| o.dna.genes.len()).max().unwrap_or(0);
+    let mut sum = vec![0.0f64; len];
+    let mut sum_sq = vec![0.0f64; len];
+    let mut count = vec![0usize; len];
+    for organism in population {
+        for (i, &g) in organism.dna.genes.iter().enumerate() {
+            sum[i] += g as f64;
+            sum_sq[i] += (g as f64) * (g as f64);
+            count[i] += 1;
+        }
+    }
+
+    (0..len)
+        .map(|i| {
+            let n = count[i] as f64;
+            let mean = sum[i] / n;
+            let variance = (sum_sq[i] / n - mean * mean).max(0.0);
+            (mean as f32, variance as f32)
+        })
+        .unzip()
+}
+
+/// Mean pairwise [`genetic_distance`] over an evenly spaced sample.
+fn diversity(population: &[Organism]) -> f32 {
+    let step = population.len().div_ceil(DIVERSITY_SAMPLE).max(1);
+    let sample: Vec<&Organism> = population.iter().step_by(step).collect();
+    if sample.len() < 2 {
+        return 0.0;
+    }
+
+    let mut total = 0.0;
+    let mut pairs = 0usize;
+    for (i, a) in sample.iter().enumerate() {
+        for b in &sample[i + 1..] {
+            total += genetic_distance(&a.dna, &b.dna);
+            pairs += 1;
+        }
+    }
+    total / pairs as f32
+}
+
+fn unique_genotypes(population: &[Organism]) -> usize {
+    population
+        .iter()
+        .map(|o| o.dna.genes.iter().map(|g| g.to_bits()).collect::<Vec<u32>>())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{Dna, OrganismId};
+
+    fn organism(genes: Vec<f32>, fitness: f32) -> Organism {
+        Organism { fitness, ..Organism::new(OrganismId(0), Dna::from_genes(genes)) }
+    }
+
+    #[test]
+    fn summarises_fitness_genes_and_diversity() {
+        let population = vec![
+            organism(vec![0.0, 1.0], 1.0),
+            organism(vec![0.0, 1.0], 2.0),
+            organism(vec![1.0, 1.0, 0.5], 3.0),
+            organism(vec![1.0, 1.0], 6.0),
+        ];
+        let stats = population_stats(&population);
+
+        assert_eq!(stats.size, 4);
+        assert_eq!((stats.best, stats.worst, stats.mean, stats.median), (6.0, 1.0, 3.0, 2.5));
+        assert!((stats.std_dev - 3.5_f32.sqrt()).abs() < 1e-6);
+        assert_eq!(stats.gene_means, vec![0.5, 1.0, 0.5]);
+        assert_eq!(stats.gene_variances, vec![0.25, 0.0, 0.0]);
+        assert_eq!(stats.unique_genotypes, 3);
+        assert!(stats.diversity > 0.0 && stats.diversity < 1.0);
+
+        assert_eq!(population_stats(&population[..1]).diversity, 0.0);
+        assert_eq!(population_stats(&[]), PopulationStats::default());
+    }
+}
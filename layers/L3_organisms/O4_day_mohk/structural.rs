@@ -0,0 +1,112 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Structural (length-changing) mutations for the Phoenix Engine.
+//!
+//! Point mutations from L0 only change gene values. The operators here grow
+//! and shrink genomes so organisms can evolve complexity over time: a new
+//! random gene may be inserted, an existing one deleted, or a short segment
+//! duplicated in place. Genome length always stays within
+//! `min_len..=max_len`.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::genealogy::MutationRecord;
+use super::phoenix_engine::Dna;
+
+/// Per-genome probabilities of each structural event, applied after the
+/// point mutation. All rates default to zero (fixed-length genomes).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StructuralMutation {
+    /// Probability of inserting one uniformly random gene.
+    pub insertion_rate: f32,
+    /// Probability of deleting one gene.
+    pub deletion_rate: f32,
+    /// Probability of duplicating a segment of up to `max_segment` genes.
+    pub duplication_rate: f32,
+    pub max_segment: usize,
+    pub min_len: usize,
+    pub max_len: usize,
+}
+
+impl Default for StructuralMutation {
+    fn default() -> Self {
+        Self {
+            insertion_rate: 0.0,
+            deletion_rate: 0.0,
+            duplication_rate: 0.0,
+            max_segment: 3,
+            min_len: 1,
+            max_len: 64,
+        }
+    }
+}
+
+impl StructuralMutation {
+    /// Whether any structural event can happen at all.
+    pub fn is_enabled(&self) -> bool {
+        self.insertion_rate > 0.0 || self.deletion_rate > 0.0 || self.duplication_rate > 0.0
+    }
+
+    /// Apply insertion, deletion and duplication (each at most once) to
+    /// `dna`, returning the events that happened.
+    pub fn mutate<R: Rng + ?Sized>(&self, dna: &mut Dna, rng: &mut R) -> Vec<MutationRecord> {
+        let mut applied = Vec::new();
+        if !self.is_enabled() {
+            return applied;
+        }
+        let max_len = self.max_len.max(self.min_len);
+        let genes = &mut dna.genes;
+
+        if rng.gen::<f32>() < self.insertion_rate && genes.len() < max_len {
+            let at = rng.gen_range(0..=genes.len());
+            genes.insert(at, rng.gen());
+            applied.push(MutationRecord::Insertion { at });
+        }
+
+        if rng.gen::<f32>() < self.deletion_rate && genes.len() > self.min_len {
+            let at = rng.gen_range(0..genes.len());
+            genes.remove(at);
+            applied.push(MutationRecord::Deletion { at });
+        }
+
+        let room = max_len.saturating_sub(genes.len());
+        if rng.gen::<f32>() < self.duplication_rate && !genes.is_empty() && room > 0 {
+            let len = rng.gen_range(1..=self.max_segment.max(1).min(genes.len()).min(room));
+            let start = rng.gen_range(0..=genes.len() - len);
+            let segment = genes[start..start + len].to_vec();
+            genes.splice(start + len..start + len, segment);
+            applied.push(MutationRecord::Duplication { start, len });
+        }
+        applied
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn lengths_stay_within_bounds() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let ops = StructuralMutation {
+            insertion_rate: 0.6,
+            deletion_rate: 0.5,
+            duplication_rate: 0.6,
+            max_segment: 4,
+            min_len: 2,
+            max_len: 10,
+        };
+
+        let mut dna = Dna::from_genes(vec![0.5; 4]);
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..500 {
+            ops.mutate(&mut dna, &mut rng);
+            assert!((2..=10).contains(&dna.genes.len()), "{}", dna.genes.len());
+            seen.insert(dna.genes.len());
+        }
+        assert!(seen.len() > 4, "genome length should vary: {seen:?}");
+    }
+}
@@ -0,0 +1,127 @@
+//! Layer: L3 – Organisms (O4 Day Mohk)
+//! Module: Timeline forks and cross-branch history comparison.
+//!
+//! Any L1 checkpoint can seed a new, independent engine
+//! ([`PhoenixEngine::fork_from`](super::phoenix_engine::PhoenixEngine::fork_from)),
+//! typically with different parameters. The fork keeps the parent's history
+//! up to the checkpoint and records a [`ForkOrigin`], so branches of one
+//! run can be lined up generation by generation with [`compare`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::phoenix_engine::HistoryPoint;
+
+/// Where a forked timeline branched off.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkOrigin {
+    /// Engine (run or experiment) the checkpoint belongs to.
+    pub parent: String,
+    pub checkpoint_id: String,
+    /// Generation of the checkpoint, where the histories diverge.
+    pub generation: u64,
+}
+
+/// One timeline to compare.
+#[derive(Clone, Copy, Debug)]
+pub struct Branch<'a> {
+    pub id: &'a str,
+    pub origin: Option<&'a ForkOrigin>,
+    pub history: &'a [HistoryPoint],
+}
+
+/// A branch's history on the shared generation axis (`None` where the
+/// branch has no point for a generation).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BranchSeries {
+    pub id: String,
+    pub origin: Option<ForkOrigin>,
+    pub best_fitness: Vec<Option<f32>>,
+    pub mean_fitness: Vec<Option<f32>>,
+    /// Best fitness at the branch's latest generation.
+    pub final_best: Option<f32>,
+}
+
+/// Fitness histories of several branches, aligned by generation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TimelineComparison {
+    pub generations: Vec<u64>,
+    pub branches: Vec<BranchSeries>,
+    /// Branch with the highest final best fitness.
+    pub leader: Option<String>,
+}
+
+/// Align the histories of `branches` on the union of their generations.
+/// If a branch has several points for one generation (a rebirth), the
+/// last one counts.
+pub fn compare(branches: &[Branch<'_>]) -> TimelineComparison {
+    let generations: Vec<u64> = branches
+        .iter()
+        .flat_map(|b| b.history.iter().map(|p| p.generation))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let series: Vec<BranchSeries> = branches
+        .iter()
+        .map(|branch| {
+            let by_generation: BTreeMap<u64, &HistoryPoint> =
+                branch.history.iter().map(|p| (p.generation, p)).collect();
+            let at = |g: &u64| by_generation.get(g);
+            BranchSeries {
+                id: branch.id.to_string(),
+                origin: branch.origin.cloned(),
+                best_fitness: generations.iter().map(|g| at(g).map(|p| p.best_fitness)).collect(),
+                mean_fitness: generations.iter().map(|g| at(g).map(|p| p.stats.mean)).collect(),
+                final_best: by_generation.values().next_back().map(|p| p.best_fitness),
+            }
+        })
+        .collect();
+
+    let leader = series
+        .iter()
+        .filter_map(|s| s.final_best.map(|best| (s, best)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(s, _)| s.id.clone());
+    TimelineComparison { generations, branches: series, leader }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(points: &[(u64, f32)]) -> Vec<HistoryPoint> {
+        points
+            .iter()
+            .map(|&(generation, best)| HistoryPoint {
+                generation,
+                best_fitness: best,
+                species_count: 0,
+                species_sizes: Vec::new(),
+                mutation: None,
+                stats: Default::default(),
+                rebirth: None,
+                hypervolume: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aligns_branches_on_shared_generations() {
+        let main = history(&[(1, 1.0), (2, 2.0), (3, 2.5)]);
+        let fork = history(&[(1, 1.0), (2, 2.0), (3, 3.0), (3, 3.5), (4, 4.0)]);
+        let origin = ForkOrigin { parent: "main".into(), checkpoint_id: "gen2".into(), generation: 2 };
+
+        let comparison = compare(&[
+            Branch { id: "main", origin: None, history: &main },
+            Branch { id: "fork", origin: Some(&origin), history: &fork },
+        ]);
+        assert_eq!(comparison.generations, vec![1, 2, 3, 4]);
+        assert_eq!(comparison.branches[0].best_fitness, vec![Some(1.0), Some(2.0), Some(2.5), None]);
+        assert_eq!(comparison.branches[1].best_fitness, vec![Some(1.0), Some(2.0), Some(3.5), Some(4.0)]);
+        assert_eq!(comparison.branches[1].origin.as_ref(), Some(&origin));
+        assert_eq!(comparison.leader.as_deref(), Some("fork"));
+        assert_eq!(compare(&[]).leader, None);
+    }
+}
@@ -2,7 +2,7 @@
 //! Module: Authentication & Identity System (Database Backed)
 
 use serde::{Deserialize, Serialize};
-use jsonwebtoken::{encode, Header, EncodingKey};
+use jsonwebtoken::{decode, encode, DecodingKey, Header, EncodingKey, Validation};
 use chrono::{Utc, Duration};
 use bcrypt::{hash, verify, DEFAULT_COST};
 use sqlx::{SqlitePool, Row};
@@ -89,4 +89,15 @@ impl AuthService {
             Err("Invalid password".to_string())
         }
     }
+
+    /// Username of a token issued by `login`, if it is valid and unexpired.
+    pub fn verify_token(&self, token: &str) -> Result<String, String> {
+        decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_ref()),
+            &Validation::default(),
+        )
+        .map(|data| data.claims.sub)
+        .map_err(|e| format!("Invalid token: {}", e))
+    }
 }
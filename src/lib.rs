@@ -16,6 +16,9 @@ pub mod layers {
     #[path = "C:/OMNIXIUS/layers/L1_chronos/economy.rs"]
     pub mod l1_economy;
 
+    #[path = "C:/OMNIXIUS/layers/L1_chronos/history_store.rs"]
+    pub mod l1_history;
+
     #[path = "C:/OMNIXIUS/layers/L2_noosphere/ai_oracle.rs"]
     pub mod l2_noosphere;
 
@@ -32,6 +35,51 @@ pub mod layers {
         pub mod o4_day_mohk {
             #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/phoenix_engine.rs"]
             pub mod phoenix_engine;
+
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/fitness.rs"]
+            pub mod fitness;
+
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/selection.rs"]
+            pub mod selection;
+
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/crossover.rs"]
+            pub mod crossover;
+
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/islands.rs"]
+            pub mod islands;
+
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/speciation.rs"]
+            pub mod speciation;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/mutation_schedule.rs"]
+            pub mod mutation_schedule;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/structural.rs"]
+            pub mod structural;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/genealogy.rs"]
+            pub mod genealogy;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/statistics.rs"]
+            pub mod statistics;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/run_controller.rs"]
+            pub mod run_controller;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/hall_of_fame.rs"]
+            pub mod hall_of_fame;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/rebirth.rs"]
+            pub mod rebirth;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/evaluation.rs"]
+            pub mod evaluation;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/packed.rs"]
+            pub mod packed;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/benchmarks.rs"]
+            pub mod benchmarks;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/nsga2.rs"]
+            pub mod nsga2;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/optimizers.rs"]
+            pub mod optimizers;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/fitness_dsl.rs"]
+            pub mod fitness_dsl;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/registry.rs"]
+            pub mod registry;
+            #[path = "C:/OMNIXIUS/layers/L3_organisms/O4_day_mohk/timeline.rs"]
+            pub mod timeline;
         }
     }
 
@@ -55,11 +103,15 @@ pub mod layers {
 
 pub mod api {
     use axum::{
-        routing::{get, post},
-        Json, Router, extract::{State, Path},
+        routing::{delete, get, post},
+        Json, Router, extract::{State, Path, Query},
+        http::{header::AUTHORIZATION, HeaderMap, StatusCode},
     };
     use tower_http::cors::CorsLayer;
     use serde::{Serialize, Deserialize};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::HashSet;
     use std::sync::{Arc, Mutex};
     use std::time::{SystemTime, UNIX_EPOCH};
     
@@ -68,12 +120,27 @@ pub mod api {
     use crate::layers::l0_ops::QuantumService;
     use crate::layers::l1_chronos::L1ChronosFileStorage;
     use crate::layers::l1_economy::{EconomyService, Wallet, LeaderboardEntry};
+    use crate::layers::l1_history::{HistoryBucket, HistoryStore};
     use crate::layers::l2_noosphere::NoosphereService;
     use crate::layers::l2_academy::AcademyService;
     use crate::layers::l2_investments::{InvestmentService, Asset, Investment};
     use crate::layers::l2_quests::{QuestService, Quest};
     use crate::layers::l3_organisms::o4_day_mohk::phoenix_engine::{
-        PhoenixEngine, Organism
+        BlockchainStorage, PhoenixEngine, Organism, OrganismId, EvolutionConfig, HistoryPoint, Dna
+    };
+    use crate::layers::l3_organisms::o4_day_mohk::genealogy::{FamilyTree, LineageRecord};
+    use crate::layers::l3_organisms::o4_day_mohk::fitness_dsl::{DslError, DslLimits, FitnessProgram, ProgramInfo};
+    use crate::layers::l3_organisms::o4_day_mohk::registry::{
+        valid_engine_id, EngineRegistry, QuotaUsage, RegisteredEngine
+    };
+    use crate::layers::l3_organisms::o4_day_mohk::timeline::{self, Branch, ForkOrigin, TimelineComparison};
+    use crate::layers::l3_organisms::o4_day_mohk::statistics::PopulationStats;
+    use crate::layers::l3_organisms::o4_day_mohk::hall_of_fame::HallOfFameEntry;
+    use crate::layers::l3_organisms::o4_day_mohk::run_controller::{
+        RunController, RunProgress, RunReport, StopHandle, TerminationCriteria
+    };
+    use crate::layers::l3_organisms::o4_day_mohk::islands::{
+        IslandModel, IslandStats, MigrationConfig, MigrationEvent
     };
     use crate::layers::l4_oikoumene::auth::AuthService;
     use crate::layers::l4_oikoumene::social::SocialService;
@@ -81,16 +148,11 @@ pub mod api {
     use crate::layers::l6_events::{EventService, GlobalEvent};
     use crate::layers::l6_day_mohk::{DayMohkService, GeoLocation};
 
-    #[derive(Serialize, Clone)]
-    pub struct HistoryPoint {
-        pub generation: u64,
-        pub best_fitness: f32,
-    }
-
     #[derive(Serialize)]
     pub struct EvolutionResponse {
         pub generation: u64,
         pub population: Vec<Organism>,
+        pub stats: PopulationStats,
         pub history: Vec<HistoryPoint>,
         pub new_balance: Option<f64>,
     }
@@ -98,6 +160,9 @@ pub mod api {
     pub struct AppState {
         pub energy: Arc<Mutex<EnergyService>>,
         pub engine: Arc<Mutex<PhoenixEngine<L0QuantumMutator, L1ChronosFileStorage>>>,
+        pub islands: Arc<Mutex<IslandModel<L0QuantumMutator, L1ChronosFileStorage>>>,
+        pub history: Arc<HistoryStore>,
+        pub run: Arc<Mutex<RunState>>,
         pub auth: Arc<AuthService>,
         pub economy: Arc<EconomyService>,
         pub comms: Arc<CommunicationService>,
@@ -107,8 +172,44 @@ pub mod api {
         pub quests: Arc<QuestService>,
         pub events: Arc<Mutex<EventService>>,
         pub day_mohk: Arc<Mutex<Vec<GeoLocation>>>,
-        pub history: Arc<Mutex<Vec<HistoryPoint>>>,
         pub last_activity: Arc<Mutex<std::collections::HashMap<String, u64>>>,
+        /// Private engines of every user; `engine` above stays the shared world.
+        pub registry: Arc<Mutex<EngineRegistry<L0QuantumMutator, L1ChronosFileStorage>>>,
+        /// L1 storage of the world engine; each tenant gets directories below it.
+        pub storage: L1ChronosFileStorage,
+        /// Users allowed to change the world engine's parameters.
+        pub admins: HashSet<String>,
+        /// Unix seconds this process started; part of its history run IDs,
+        /// so a restart never overwrites an earlier run's history.
+        pub started_at: u64,
+    }
+
+    /// Engine shared between request handlers.
+    pub type SharedEngine = Arc<Mutex<PhoenixEngine<L0QuantumMutator, L1ChronosFileStorage>>>;
+
+    /// Controlled run of the world engine (see `/api/run/*`).
+    #[derive(Default)]
+    pub struct RunState {
+        pub criteria: Option<TerminationCriteria>,
+        pub progress: Option<RunProgress>,
+        /// Outcome of the last finished run.
+        pub report: Option<RunReport>,
+        stop: Option<StopHandle>,
+    }
+
+    impl RunState {
+        /// Whether a controlled run currently drives the world engine.
+        pub fn is_running(&self) -> bool {
+            self.stop.is_some()
+        }
+    }
+
+    #[derive(Serialize)]
+    pub struct RunStatus {
+        pub running: bool,
+        pub criteria: Option<TerminationCriteria>,
+        pub progress: Option<RunProgress>,
+        pub report: Option<RunReport>,
     }
 
     #[derive(Serialize)]
@@ -117,13 +218,23 @@ pub mod api {
         pub layer: String,
         pub version: String,
         pub generation: u64,
+        pub seed: u64,
         pub population_size: usize,
         pub population: Vec<Organism>,
+        pub stats: PopulationStats,
         pub history: Vec<HistoryPoint>,
         pub energy: EnergyState,
         pub current_event: GlobalEvent,
     }
 
+    #[derive(Serialize)]
+    pub struct IslandsResponse {
+        pub generation: u64,
+        pub migration: MigrationConfig,
+        pub islands: Vec<IslandStats>,
+        pub migrations: Vec<MigrationEvent>,
+    }
+
     #[derive(Serialize)]
     pub struct UserData {
         pub subscriptions: Vec<String>,
@@ -203,9 +314,26 @@ pub mod api {
         pub organism_id: u64,
     }
 
-    pub async fn get_status(State(state): State<Arc<AppState>>) -> Json<SystemStatus> {
+    /// Number of history points returned by `/api/status` and `/api/evolve`.
+    const API_HISTORY_LEN: usize = 50;
+
+    fn recent_history(history: &[HistoryPoint]) -> Vec<HistoryPoint> {
+        history[history.len().saturating_sub(API_HISTORY_LEN)..].to_vec()
+    }
+
+    #[derive(Deserialize)]
+    pub struct StatusQuery {
+        /// Maximum number of organisms in `population` (the first ones; all
+        /// of them if unset). `population_size` always reports the full count.
+        pub population_limit: Option<usize>,
+    }
+
+    pub async fn get_status(
+        State(state): State<Arc<AppState>>,
+        Query(query): Query<StatusQuery>,
+    ) -> Json<SystemStatus> {
+        let limit = query.population_limit.unwrap_or(usize::MAX);
         let engine = state.engine.lock().unwrap();
-        let history = state.history.lock().unwrap();
         let mut energy_svc = state.energy.lock().unwrap();
         let mut event_svc = state.events.lock().unwrap();
         
@@ -217,9 +345,11 @@ pub mod api {
             layer: engine.layer_id.to_string(),
             version: "0.1.0-alpha".to_string(),
             generation: engine.generation,
+            seed: engine.seed(),
             population_size: engine.population_size(),
-            population: engine.population.clone(),
-            history: history.clone(),
+            population: engine.population.iter().take(limit).cloned().collect(),
+            stats: engine.stats(),
+            history: recent_history(&engine.history),
             energy: energy_state,
             current_event,
         })
@@ -234,6 +364,35 @@ pub mod api {
         Json(locations.clone())
     }
 
+    fn islands_response(model: &IslandModel<L0QuantumMutator, L1ChronosFileStorage>) -> IslandsResponse {
+        IslandsResponse {
+            generation: model.generation,
+            migration: model.migration.clone(),
+            islands: model.stats(),
+            migrations: model.events[model.events.len().saturating_sub(API_HISTORY_LEN)..].to_vec(),
+        }
+    }
+
+    pub async fn get_islands(State(state): State<Arc<AppState>>) -> Json<IslandsResponse> {
+        let model = state.islands.lock().unwrap();
+        Json(islands_response(&model))
+    }
+
+    /// One generation of every island, charged to the caller's hourly
+    /// generation quota.
+    pub async fn evolve_islands(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+    ) -> Result<Json<IslandsResponse>, (StatusCode, String)> {
+        let user = authenticated_user(&state, &headers).map_err(|e| (StatusCode::UNAUTHORIZED, e))?;
+        if let Err(e) = state.registry.lock().unwrap().reserve_generations(&user, 1, unix_now()) {
+            return Err((StatusCode::TOO_MANY_REQUESTS, e));
+        }
+        let mut model = state.islands.lock().unwrap();
+        model.evolve();
+        Ok(Json(islands_response(&model)))
+    }
+
     pub async fn deploy_organism(
         State(state): State<Arc<AppState>>,
         Json(payload): Json<DeployRequest>,
@@ -383,31 +542,373 @@ pub mod api {
         ])
     }
 
-    pub async fn trigger_evolution(State(state): State<Arc<AppState>>) -> Json<EvolutionResponse> {
-        let mut engine = state.engine.lock().unwrap();
-        let mut rng = rand::thread_rng();
-        
-        let pop_size = engine.population_size();
-        engine.evolve(&mut rng, pop_size);
+    /// Evolve `engine` by one generation (`offspring_count` defaults to the
+    /// population size). The lock is only held to breed and to commit; the
+    /// offspring are scored on the blocking thread pool in between, so other
+    /// requests are not stalled by the fitness function. Returns `false` if
+    /// another generation was committed meanwhile and this one was dropped.
+    ///
+    /// Non-GA optimizers score their candidates between their own steps, so
+    /// they run the whole generation under the lock.
+    pub async fn evolve_unlocked(
+        engine: &Mutex<PhoenixEngine<L0QuantumMutator, L1ChronosFileStorage>>,
+        offspring_count: Option<usize>,
+    ) -> bool {
+        let brood = {
+            let mut engine = engine.lock().unwrap();
+            let offspring_count = offspring_count.unwrap_or_else(|| engine.population_size());
+            if engine.uses_optimizer() {
+                let generation = engine.generation;
+                engine.evolve(offspring_count);
+                return engine.generation != generation;
+            }
+            engine.breed(offspring_count)
+        };
+        let Some(brood) = brood else { return false };
+        match tokio::task::spawn_blocking(move || brood.evaluated()).await {
+            Ok(brood) => engine.lock().unwrap().commit(brood),
+            Err(e) => {
+                eprintln!("[Evolution] fitness evaluation failed: {e}");
+                false
+            }
+        }
+    }
+
+    /// One generation of the world engine, charged to the caller's hourly
+    /// generation quota. Refused while a run owns the engine.
+    pub async fn trigger_evolution(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+    ) -> Result<Json<EvolutionResponse>, (StatusCode, String)> {
+        let user = authenticated_user(&state, &headers).map_err(|e| (StatusCode::UNAUTHORIZED, e))?;
+        if state.run.lock().unwrap().is_running() {
+            return Err((StatusCode::CONFLICT, "a run is in progress".to_string()));
+        }
+        if let Err(e) = state.registry.lock().unwrap().reserve_generations(&user, 1, unix_now()) {
+            return Err((StatusCode::TOO_MANY_REQUESTS, e));
+        }
+        let evolved = evolve_unlocked(&state.engine, None).await;
+        if !evolved {
+            state.registry.lock().unwrap().refund_generations(&user, 1);
+        }
+        let (response, point) = {
+            let engine = state.engine.lock().unwrap();
+
+            let response = EvolutionResponse {
+                generation: engine.generation,
+                population: engine.population.clone(),
+                stats: engine.stats(),
+                history: recent_history(&engine.history),
+                new_balance: None,
+            };
+            // A dropped brood left someone else's generation as the last point.
+            (response, engine.history.last().cloned().filter(|_| evolved))
+        };
+
+        if let Some(point) = point {
+            if let Err(e) = state.history.record(&history_run_id(WORLD_RUN_ID, state.started_at), &point).await {
+                eprintln!("[History] failed to persist generation {}: {e}", point.generation);
+            }
+        }
+        Ok(Json(response))
+    }
+
+    fn run_status(run: &RunState) -> RunStatus {
+        RunStatus {
+            running: run.is_running(),
+            criteria: run.criteria.clone(),
+            progress: run.progress.clone(),
+            report: run.report.clone(),
+        }
+    }
+
+    /// Longest controlled run of the world engine, in seconds.
+    const MAX_RUN_SECONDS: f64 = 3_600.0;
+
+    /// Evolve the world engine until `criteria` hold (admins only; at most
+    /// [`MAX_RUN_SECONDS`]). The 30 s auto-evolution pauses while a run is
+    /// active.
+    pub async fn start_run(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Json(mut criteria): Json<TerminationCriteria>,
+    ) -> Json<Result<RunStatus, String>> {
+        if let Err(e) = admin_user(&state, &headers) {
+            return Json(Err(e));
+        }
+        // The run holds the world engine, so it must end on its own.
+        if !criteria.is_bounded() {
+            return Json(Err("set max_generations, max_evaluations or max_seconds".to_string()));
+        }
+        criteria.max_seconds = Some(criteria.max_seconds.map_or(MAX_RUN_SECONDS, |s| s.min(MAX_RUN_SECONDS)));
+        let mut controller = {
+            let engine = state.engine.lock().unwrap();
+            RunController::new(criteria.clone(), &engine)
+        };
+        {
+            let mut run = state.run.lock().unwrap();
+            if run.is_running() {
+                return Json(Err("a run is already in progress".to_string()));
+            }
+            *run = RunState {
+                criteria: Some(criteria),
+                progress: Some(RunProgress::default()),
+                report: None,
+                stop: Some(controller.stop_handle()),
+            };
+        }
+
+        let task_state = Arc::clone(&state);
+        let run_id = history_run_id(WORLD_RUN_ID, state.started_at);
+        tokio::spawn(async move {
+            loop {
+                let (done, generation) = {
+                    let engine = task_state.engine.lock().unwrap();
+                    (controller.check(&engine), engine.generation)
+                };
+                let evolved =
+                    done.is_none() && evolve_unlocked(&task_state.engine, controller.offspring_count).await;
+                let (reason, progress, point) = {
+                    let engine = task_state.engine.lock().unwrap();
+                    // Only a committed generation counts towards stagnation; a
+                    // dropped one with nothing left to breed ends the run.
+                    let reason = done.or_else(|| {
+                        if evolved {
+                            controller.observe(&engine)
+                        } else {
+                            RunController::stalled(&engine, generation).or_else(|| controller.check(&engine))
+                        }
+                    });
+                    (reason, controller.progress(&engine), engine.history.last().cloned().filter(|_| evolved))
+                };
+                if let Some(point) = point {
+                    if let Err(e) = task_state.history.record(&run_id, &point).await {
+                        eprintln!("[History] failed to persist generation {}: {e}", point.generation);
+                    }
+                }
+
+                {
+                    let mut run = task_state.run.lock().unwrap();
+                    run.progress = Some(progress.clone());
+                    if let Some(reason) = reason {
+                        println!("[Run] finished after {} generations: {:?}", progress.generations, reason);
+                        run.report = Some(RunReport { reason, progress });
+                        run.stop = None;
+                        break;
+                    }
+                }
+                tokio::task::yield_now().await;
+            }
+        });
+
+        let run = state.run.lock().unwrap();
+        Json(Ok(run_status(&run)))
+    }
+
+    /// Cancel the active run; it reports `cancelled` after its current generation.
+    pub async fn stop_run(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+    ) -> Json<Result<RunStatus, String>> {
+        if let Err(e) = admin_user(&state, &headers) {
+            return Json(Err(e));
+        }
+        let run = state.run.lock().unwrap();
+        match &run.stop {
+            Some(stop) => {
+                stop.stop();
+                Json(Ok(run_status(&run)))
+            }
+            None => Json(Err("no run in progress".to_string())),
+        }
+    }
+
+    pub async fn get_run_status(State(state): State<Arc<AppState>>) -> Json<RunStatus> {
+        let run = state.run.lock().unwrap();
+        Json(run_status(&run))
+    }
+
+    /// Name of the world engine (as a fork parent, timeline branch and
+    /// history run).
+    pub const WORLD_RUN_ID: &str = "world";
+
+    /// History store run ID of engine `name` in a process started at
+    /// `started_at`, e.g. `world/1700000000` or `island/grozny/1700000000`.
+    pub fn history_run_id(name: &str, started_at: u64) -> String {
+        format!("{name}/{started_at}")
+    }
+
+    /// Default number of buckets returned by `/api/history`.
+    const DEFAULT_HISTORY_RESOLUTION: u64 = 500;
+
+    #[derive(Deserialize)]
+    pub struct HistoryQuery {
+        /// Run to query (default: this process's world engine run; see
+        /// `/api/history/runs` for earlier ones).
+        pub run: Option<String>,
+        pub from: Option<u64>,
+        /// Last generation (default: the latest stored one).
+        pub to: Option<u64>,
+        pub resolution: Option<u64>,
+    }
+
+    pub async fn get_history(
+        State(state): State<Arc<AppState>>,
+        Query(query): Query<HistoryQuery>,
+    ) -> Json<Result<Vec<HistoryBucket>, String>> {
+        let run = query.run.unwrap_or_else(|| history_run_id(WORLD_RUN_ID, state.started_at));
+        let to = match query.to {
+            Some(to) => to,
+            None => match state.history.latest_generation(&run).await {
+                Ok(Some(latest)) => latest,
+                Ok(None) => return Json(Ok(Vec::new())),
+                Err(e) => return Json(Err(e)),
+            },
+        };
+        let resolution = query.resolution.unwrap_or(DEFAULT_HISTORY_RESOLUTION);
+        Json(state.history.query(&run, query.from.unwrap_or(0), to, resolution).await)
+    }
+
+    /// Every run in the history store, this process's and earlier ones.
+    pub async fn get_history_runs(State(state): State<Arc<AppState>>) -> Json<Result<Vec<String>, String>> {
+        Json(state.history.runs().await)
+    }
+
+    pub async fn get_evolution_config(State(state): State<Arc<AppState>>) -> Json<EvolutionConfig> {
+        let engine = state.engine.lock().unwrap();
+        Json(engine.config.clone())
+    }
 
-        let mut best_f = 0.0;
-        for org in &mut engine.population {
-            org.fitness = org.dna.genes.iter().sum::<f32>() * 2.0;
-            if org.fitness > best_f { best_f = org.fitness; }
+    /// Replace the world engine's parameters (admins only).
+    pub async fn update_evolution_config(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Json(config): Json<EvolutionConfig>,
+    ) -> Json<Result<EvolutionConfig, String>> {
+        if let Err(e) = admin_user(&state, &headers) {
+            return Json(Err(e));
+        }
+        let quota = state.registry.lock().unwrap().quota;
+        let mut engine = state.engine.lock().unwrap();
+        let genes = engine.population.iter().map(|o| o.dna.genes.len()).max().unwrap_or(0);
+        if let Err(e) = quota.check_config(&config, genes, DslLimits::default().max_genes) {
+            return Json(Err(e));
         }
+        engine.set_config(config);
+        Json(Ok(engine.config.clone()))
+    }
 
-        let mut history = state.history.lock().unwrap();
-        history.push(HistoryPoint { generation: engine.generation, best_fitness: best_f });
-        if history.len() > 50 { history.remove(0); }
+    #[derive(Serialize)]
+    pub struct ParetoFrontResponse {
+        pub generation: u64,
+        pub multi_objective: bool,
+        pub hypervolume: Option<f64>,
+        /// Non-dominated organisms, with their objective vectors.
+        pub front: Vec<Organism>,
+    }
 
-        Json(EvolutionResponse {
+    /// Current Pareto front of the world engine (empty unless it runs in
+    /// multi-objective mode).
+    pub async fn get_pareto_front(State(state): State<Arc<AppState>>) -> Json<ParetoFrontResponse> {
+        let engine = state.engine.lock().unwrap();
+        Json(ParetoFrontResponse {
             generation: engine.generation,
-            population: engine.population.clone(),
-            history: history.clone(),
-            new_balance: None,
+            multi_objective: engine.is_multi_objective(),
+            hypervolume: engine.hypervolume(),
+            front: engine.pareto_front().into_iter().cloned().collect(),
         })
     }
 
+    pub async fn get_hall_of_fame(State(state): State<Arc<AppState>>) -> Json<Vec<HallOfFameEntry>> {
+        let engine = state.engine.lock().unwrap();
+        Json(engine.hall_of_fame.entries().to_vec())
+    }
+
+    /// Copy a champion back into the world population (replacing the weakest).
+    /// Put a hall-of-fame champion back into the world (admins only).
+    pub async fn reintroduce_champion(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(id): Path<u64>,
+    ) -> Json<Result<(), String>> {
+        if let Err(e) = admin_user(&state, &headers) {
+            return Json(Err(e));
+        }
+        let mut engine = state.engine.lock().unwrap();
+        if engine.reintroduce_champion(&OrganismId(id)) {
+            Json(Ok(()))
+        } else {
+            Json(Err(format!("organism {id} is not in the hall of fame")))
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct LineageQuery {
+        /// Generations to walk (default [`DEFAULT_LINEAGE_DEPTH`]).
+        pub depth: Option<usize>,
+    }
+
+    const DEFAULT_LINEAGE_DEPTH: usize = 5;
+
+    impl LineageQuery {
+        fn depth(&self) -> usize {
+            self.depth.unwrap_or(DEFAULT_LINEAGE_DEPTH)
+        }
+    }
+
+    pub async fn get_ancestors(
+        State(state): State<Arc<AppState>>,
+        Path(id): Path<u64>,
+        Query(query): Query<LineageQuery>,
+    ) -> Json<Result<Vec<LineageRecord>, String>> {
+        let engine = state.engine.lock().unwrap();
+        let id = OrganismId(id);
+        if engine.genealogy.get(&id).is_none() {
+            return Json(Err(format!("unknown organism {}", id.0)));
+        }
+        Json(Ok(engine.genealogy.ancestors(&id, query.depth()).into_iter().cloned().collect()))
+    }
+
+    pub async fn get_descendants(
+        State(state): State<Arc<AppState>>,
+        Path(id): Path<u64>,
+        Query(query): Query<LineageQuery>,
+    ) -> Json<Result<Vec<LineageRecord>, String>> {
+        let engine = state.engine.lock().unwrap();
+        let id = OrganismId(id);
+        if engine.genealogy.get(&id).is_none() {
+            return Json(Err(format!("unknown organism {}", id.0)));
+        }
+        Json(Ok(engine.genealogy.descendants(&id, query.depth()).into_iter().cloned().collect()))
+    }
+
+    fn family_tree(state: &AppState, id: u64, depth: usize) -> Result<FamilyTree, String> {
+        let engine = state.engine.lock().unwrap();
+        engine
+            .genealogy
+            .family_tree(&OrganismId(id), depth)
+            .ok_or_else(|| format!("unknown organism {id}"))
+    }
+
+    pub async fn get_family_tree(
+        State(state): State<Arc<AppState>>,
+        Path(id): Path<u64>,
+        Query(query): Query<LineageQuery>,
+    ) -> Json<Result<FamilyTree, String>> {
+        Json(family_tree(&state, id, query.depth()))
+    }
+
+    /// Graphviz DOT rendering of the family tree (plain text).
+    pub async fn get_family_tree_dot(
+        State(state): State<Arc<AppState>>,
+        Path(id): Path<u64>,
+        Query(query): Query<LineageQuery>,
+    ) -> Result<String, (StatusCode, String)> {
+        family_tree(&state, id, query.depth())
+            .map(|tree| tree.to_dot())
+            .map_err(|e| (StatusCode::NOT_FOUND, e))
+    }
+
     pub async fn get_wallet(
         State(state): State<Arc<AppState>>,
         Path(username): Path<String>,
@@ -438,6 +939,460 @@ pub mod api {
         Json(NoosphereService::get_advice())
     }
 
+    /// Username of the request's `Authorization: Bearer <token>` header.
+    fn authenticated_user(state: &AppState, headers: &HeaderMap) -> Result<String, String> {
+        let token = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or("missing bearer token")?;
+        state.auth.verify_token(token)
+    }
+
+    /// Like [`authenticated_user`], but only for `state.admins`, who may
+    /// steer the shared world engine.
+    fn admin_user(state: &AppState, headers: &HeaderMap) -> Result<String, String> {
+        let user = authenticated_user(state, headers)?;
+        if !state.admins.contains(&user) {
+            return Err("only admins may steer the world engine".to_string());
+        }
+        Ok(user)
+    }
+
+    /// Parse and validate a fitness program without running it.
+    pub async fn validate_fitness(Json(program): Json<FitnessProgram>) -> Json<Result<ProgramInfo, DslError>> {
+        Json(program.compile(&DslLimits::default()).map(|compiled| compiled.info()))
+    }
+
+    const DEFAULT_EXPERIMENT_POPULATION: usize = 32;
+    const DEFAULT_EXPERIMENT_DNA_LEN: usize = 8;
+    /// Generations one `/api/experiments/:name/evolve` request may run.
+    const MAX_EXPERIMENT_GENERATIONS: u64 = 100;
+
+    fn unix_now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    #[derive(Deserialize)]
+    pub struct CreateExperimentRequest {
+        /// 1–64 ASCII letters, digits, `-` or `_`.
+        pub name: String,
+        pub program: FitnessProgram,
+        pub population_size: Option<usize>,
+        pub dna_len: Option<usize>,
+        pub seed: Option<u64>,
+        pub config: Option<EvolutionConfig>,
+    }
+
+    #[derive(Deserialize)]
+    pub struct EvolveExperimentRequest {
+        pub generations: Option<u64>,
+    }
+
+    #[derive(Serialize)]
+    pub struct ExperimentStatus {
+        pub name: String,
+        pub program: FitnessProgram,
+        pub created_at: u64,
+        /// Checkpoint the experiment was forked from.
+        pub origin: Option<ForkOrigin>,
+        pub generation: u64,
+        pub seed: u64,
+        pub population_size: usize,
+        pub evaluations: u64,
+        pub stats: PopulationStats,
+        pub best: Option<Organism>,
+        pub history: Vec<HistoryPoint>,
+    }
+
+    fn experiment_status(
+        name: &str,
+        program: &FitnessProgram,
+        created_at: u64,
+        engine: &PhoenixEngine<L0QuantumMutator, L1ChronosFileStorage>,
+    ) -> ExperimentStatus {
+        ExperimentStatus {
+            name: name.to_string(),
+            program: program.clone(),
+            created_at,
+            origin: engine.origin().cloned(),
+            generation: engine.generation,
+            seed: engine.seed(),
+            population_size: engine.population_size(),
+            evaluations: engine.evaluations(),
+            stats: engine.stats(),
+            best: engine.population.iter().max_by(|a, b| a.fitness.total_cmp(&b.fitness)).cloned(),
+            history: recent_history(&engine.history),
+        }
+    }
+
+    fn lookup_experiment(
+        state: &AppState,
+        owner: &str,
+        name: &str,
+    ) -> Result<(FitnessProgram, u64, SharedEngine), String> {
+        let registry = state.registry.lock().unwrap();
+        let entry = registry.get(owner, name).ok_or_else(|| format!("no experiment named {name}"))?;
+        Ok((entry.program.clone(), entry.created_at, Arc::clone(&entry.engine)))
+    }
+
+    /// Start a private engine scored by the caller's fitness program, with
+    /// checkpoints in the caller's own L1 directory.
+    pub async fn create_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Json(request): Json<CreateExperimentRequest>,
+    ) -> Json<Result<ExperimentStatus, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        let limits = DslLimits::default();
+        let fitness = match request.program.compile(&limits) {
+            Ok(fitness) => fitness,
+            Err(e) => return Json(Err(format!("invalid fitness program {e}"))),
+        };
+        let population_size = request.population_size.unwrap_or(DEFAULT_EXPERIMENT_POPULATION);
+        let dna_len = request.dna_len.unwrap_or(DEFAULT_EXPERIMENT_DNA_LEN);
+        if population_size < 2 {
+            return Json(Err("population_size must be at least 2".to_string()));
+        }
+        if !(1..=limits.max_genes).contains(&dna_len) {
+            return Json(Err(format!("dna_len must be 1..={}", limits.max_genes)));
+        }
+        let config = request.config.unwrap_or_default();
+        {
+            let registry = state.registry.lock().unwrap();
+            let checked = registry.check_new(&owner, &request.name, population_size);
+            let checked = checked.and_then(|()| registry.quota.check_config(&config, dna_len, limits.max_genes));
+            if let Err(e) = checked {
+                return Json(Err(e));
+            }
+        }
+
+        let seed = request.seed.unwrap_or_else(rand::random);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let population = (0..population_size as u64)
+            .map(|i| Organism::new(OrganismId(i), Dna::new_random(dna_len, &mut rng)))
+            .collect();
+        let storage = state.storage.tenant(&owner, &request.name);
+        // The initial population is scored here, off the async runtime.
+        let built = tokio::task::spawn_blocking(move || {
+            PhoenixEngine::new(
+                "L3_organisms::O4_day_mohk::experiment",
+                L0QuantumMutator::default(),
+                storage,
+                fitness,
+                population,
+            )
+            .with_seed(seed)
+            .with_config(config)
+        })
+        .await;
+        let engine = match built {
+            Ok(engine) => engine,
+            Err(e) => return Json(Err(format!("experiment setup failed: {e}"))),
+        };
+
+        let created_at = unix_now();
+        let status = experiment_status(&request.name, &request.program, created_at, &engine);
+        let entry = RegisteredEngine {
+            program: request.program,
+            engine: Arc::new(Mutex::new(engine)),
+            created_at,
+        };
+        // Checked again: another request may have registered meanwhile.
+        Json(state.registry.lock().unwrap().insert(&owner, &request.name, entry).map(|()| status))
+    }
+
+    /// The caller's experiments.
+    pub async fn list_experiments(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+    ) -> Json<Result<Vec<ExperimentStatus>, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        let registry = state.registry.lock().unwrap();
+        let statuses = registry
+            .engines(&owner)
+            .map(|(name, entry)| {
+                experiment_status(name, &entry.program, entry.created_at, &entry.engine.lock().unwrap())
+            })
+            .collect();
+        Json(Ok(statuses))
+    }
+
+    pub async fn get_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(name): Path<String>,
+    ) -> Json<Result<ExperimentStatus, String>> {
+        let result = authenticated_user(&state, &headers)
+            .and_then(|owner| lookup_experiment(&state, &owner, &name))
+            .map(|(program, created_at, engine)| {
+                experiment_status(&name, &program, created_at, &engine.lock().unwrap())
+            });
+        Json(result)
+    }
+
+    /// Forget an experiment (its checkpoints stay in L1).
+    pub async fn delete_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(name): Path<String>,
+    ) -> Json<Result<(), String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        match state.registry.lock().unwrap().remove(&owner, &name) {
+            Some(_) => Json(Ok(())),
+            None => Json(Err(format!("no experiment named {name}"))),
+        }
+    }
+
+    /// Evolve one of the caller's experiments on the blocking thread pool,
+    /// for as many of the requested generations as the hourly quota allows.
+    pub async fn evolve_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(name): Path<String>,
+        Json(request): Json<EvolveExperimentRequest>,
+    ) -> Json<Result<ExperimentStatus, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        let (program, created_at, engine) = match lookup_experiment(&state, &owner, &name) {
+            Ok(found) => found,
+            Err(e) => return Json(Err(e)),
+        };
+        let requested = request.generations.unwrap_or(1).min(MAX_EXPERIMENT_GENERATIONS);
+        let (generations, max_population) = {
+            let mut registry = state.registry.lock().unwrap();
+            match registry.reserve_generations(&owner, requested, unix_now()) {
+                Ok(granted) => (granted, registry.quota.max_population),
+                Err(e) => return Json(Err(e)),
+            }
+        };
+        let evolved = tokio::task::spawn_blocking(move || {
+            let mut engine = engine.lock().unwrap();
+            for _ in 0..generations {
+                let offspring_count = engine.population_size().min(max_population);
+                engine.evolve(offspring_count);
+            }
+            experiment_status(&name, &program, created_at, &engine)
+        })
+        .await;
+        Json(evolved.map_err(|e| format!("evolution failed: {e}")))
+    }
+
+    #[derive(Deserialize)]
+    pub struct CheckpointRequest {
+        /// 1–64 ASCII letters, digits, `-` or `_`.
+        pub checkpoint_id: String,
+    }
+
+    /// Checkpoints of the world engine; any of them can be forked.
+    pub async fn list_world_checkpoints(State(state): State<Arc<AppState>>) -> Json<Result<Vec<String>, String>> {
+        Json(state.storage.checkpoint_ids().map_err(|e| e.to_string()))
+    }
+
+    /// Checkpoints in the L1 directory of one of the caller's experiments
+    /// (including experiments deleted since).
+    pub async fn list_experiment_checkpoints(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(name): Path<String>,
+    ) -> Json<Result<Vec<String>, String>> {
+        let result = authenticated_user(&state, &headers).and_then(|owner| {
+            state.storage.tenant(&owner, &name).checkpoint_ids().map_err(|e| e.to_string())
+        });
+        Json(result)
+    }
+
+    /// Store the complete state of one of the caller's experiments as a
+    /// checkpoint, e.g. as a fork point.
+    pub async fn checkpoint_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Path(name): Path<String>,
+        Json(request): Json<CheckpointRequest>,
+    ) -> Json<Result<String, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        if !valid_engine_id(&request.checkpoint_id) {
+            return Json(Err("checkpoint IDs are 1-64 letters, digits, '-' or '_'".to_string()));
+        }
+        let engine = match lookup_experiment(&state, &owner, &name) {
+            Ok((_, _, engine)) => engine,
+            Err(e) => return Json(Err(e)),
+        };
+        let stored = tokio::task::spawn_blocking(move || {
+            let checkpoint_id = request.checkpoint_id;
+            engine.lock().unwrap().checkpoint_to_blockchain(&checkpoint_id).map(|()| checkpoint_id)
+        })
+        .await;
+        match stored {
+            Ok(result) => Json(result.map_err(|e| e.to_string())),
+            Err(e) => Json(Err(format!("checkpoint failed: {e}"))),
+        }
+    }
+
+    #[derive(Deserialize)]
+    pub struct ForkRequest {
+        /// Experiment of the caller to fork (default: the world engine).
+        pub parent: Option<String>,
+        pub checkpoint_id: String,
+        /// Name of the new experiment.
+        pub name: String,
+        /// Fitness program of the fork (default: the parent experiment's;
+        /// required to fork the world engine).
+        pub program: Option<FitnessProgram>,
+        /// Parameters of the fork (default: the parent's at the checkpoint).
+        pub config: Option<EvolutionConfig>,
+        /// Reseed the fork (default: continue the parent's RNG stream).
+        pub seed: Option<u64>,
+    }
+
+    /// Branch a new private experiment from a checkpoint of the world engine
+    /// or of one of the caller's experiments.
+    pub async fn fork_experiment(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Json(request): Json<ForkRequest>,
+    ) -> Json<Result<ExperimentStatus, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        let (parent, source, parent_program) = match request.parent {
+            Some(parent) => {
+                let program = state.registry.lock().unwrap().get(&owner, &parent).map(|e| e.program.clone());
+                let source = state.storage.tenant(&owner, &parent);
+                (parent, source, program)
+            }
+            None => (WORLD_RUN_ID.to_string(), state.storage.clone(), None),
+        };
+        let program = match request.program.or(parent_program) {
+            Some(program) => program,
+            None => return Json(Err(format!("a fitness program is required to fork {parent}"))),
+        };
+        let limits = DslLimits::default();
+        let fitness = match program.compile(&limits) {
+            Ok(fitness) => fitness,
+            Err(e) => return Json(Err(format!("invalid fitness program {e}"))),
+        };
+        // The population quota is checked once the checkpoint is loaded.
+        let quota = {
+            let registry = state.registry.lock().unwrap();
+            if let Err(e) = registry.check_new(&owner, &request.name, 0) {
+                return Json(Err(e));
+            }
+            registry.quota
+        };
+
+        let storage = state.storage.tenant(&owner, &request.name);
+        let (checkpoint_id, config, seed) = (request.checkpoint_id, request.config, request.seed);
+        let built = tokio::task::spawn_blocking(move || {
+            let snapshot = source.load_snapshot(&checkpoint_id).map_err(|e| e.to_string())?;
+            if snapshot.population.len() > quota.max_population {
+                return Err(format!("population quota is {} organisms", quota.max_population));
+            }
+            // The fork runs the requested config, or inherits the checkpoint's.
+            let genes = snapshot.population.iter().map(|o| o.dna.genes.len()).max().unwrap_or(0);
+            let inherited = snapshot.state.as_ref().map(|state| &state.config);
+            if let Some(config) = config.as_ref().or(inherited) {
+                quota.check_config(config, genes, limits.max_genes)?;
+            }
+            let generation = snapshot.state.as_ref().map_or(0, |state| state.generation);
+            // `fork_from` scores the checkpoint's population, so start empty.
+            let mut engine = PhoenixEngine::new(
+                "L3_organisms::O4_day_mohk::experiment",
+                L0QuantumMutator::default(),
+                storage,
+                fitness,
+                Vec::new(),
+            );
+            let origin = ForkOrigin { parent, checkpoint_id, generation };
+            engine.fork_from(snapshot, origin).map_err(|e| e.to_string())?;
+            if let Some(config) = config {
+                engine.set_config(config);
+            }
+            Ok::<_, String>(match seed {
+                Some(seed) => engine.with_seed(seed),
+                None => engine,
+            })
+        })
+        .await;
+        let engine = match built {
+            Ok(Ok(engine)) => engine,
+            Ok(Err(e)) => return Json(Err(e)),
+            Err(e) => return Json(Err(format!("fork failed: {e}"))),
+        };
+
+        let created_at = unix_now();
+        let status = experiment_status(&request.name, &program, created_at, &engine);
+        let entry = RegisteredEngine { program, engine: Arc::new(Mutex::new(engine)), created_at };
+        Json(state.registry.lock().unwrap().insert(&owner, &request.name, entry).map(|()| status))
+    }
+
+    #[derive(Deserialize)]
+    pub struct CompareQuery {
+        /// Comma-separated names of the caller's experiments.
+        #[serde(default)]
+        pub branches: String,
+        /// Include the world engine (as branch `world`).
+        #[serde(default)]
+        pub world: bool,
+    }
+
+    /// Fitness histories of several branches, aligned by generation, for
+    /// A/B comparison of forks.
+    pub async fn compare_forks(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+        Query(query): Query<CompareQuery>,
+    ) -> Json<Result<TimelineComparison, String>> {
+        let owner = match authenticated_user(&state, &headers) {
+            Ok(owner) => owner,
+            Err(e) => return Json(Err(e)),
+        };
+        let mut timelines: Vec<(String, Option<ForkOrigin>, Vec<HistoryPoint>)> = Vec::new();
+        if query.world {
+            let engine = state.engine.lock().unwrap();
+            timelines.push((WORLD_RUN_ID.to_string(), engine.origin().cloned(), engine.history.clone()));
+        }
+        for name in query.branches.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let engine = match lookup_experiment(&state, &owner, name) {
+                Ok((_, _, engine)) => engine,
+                Err(e) => return Json(Err(e)),
+            };
+            let engine = engine.lock().unwrap();
+            timelines.push((name.to_string(), engine.origin().cloned(), engine.history.clone()));
+        }
+        let branches: Vec<Branch<'_>> = timelines
+            .iter()
+            .map(|(id, origin, history)| Branch { id, origin: origin.as_ref(), history })
+            .collect();
+        Json(Ok(timeline::compare(&branches)))
+    }
+
+    /// The caller's engine count and generations used in the last hour.
+    pub async fn get_quota(
+        State(state): State<Arc<AppState>>,
+        headers: HeaderMap,
+    ) -> Json<Result<QuotaUsage, String>> {
+        Json(
+            authenticated_user(&state, &headers)
+                .map(|owner| state.registry.lock().unwrap().usage(&owner, unix_now())),
+        )
+    }
+
     pub async fn register(
         State(state): State<Arc<AppState>>,
         Json(payload): Json<AuthRequest>,
@@ -465,6 +1420,20 @@ pub mod api {
         Router::new()
             .route("/api/status", get(get_status))
             .route("/api/evolve", post(trigger_evolution))
+            .route("/api/evolve/config", get(get_evolution_config))
+            .route("/api/evolve/config", post(update_evolution_config))
+            .route("/api/history", get(get_history))
+            .route("/api/history/runs", get(get_history_runs))
+            .route("/api/pareto-front", get(get_pareto_front))
+            .route("/api/hall-of-fame", get(get_hall_of_fame))
+            .route("/api/hall-of-fame/:id/reintroduce", post(reintroduce_champion))
+            .route("/api/run/start", post(start_run))
+            .route("/api/run/stop", post(stop_run))
+            .route("/api/run/status", get(get_run_status))
+            .route("/api/lineage/:id/ancestors", get(get_ancestors))
+            .route("/api/lineage/:id/descendants", get(get_descendants))
+            .route("/api/lineage/:id/tree", get(get_family_tree))
+            .route("/api/lineage/:id/tree.dot", get(get_family_tree_dot))
             .route("/api/quantum", get(get_quantum_state))
             .route("/api/oracle", get(get_oracle_advice))
             .route("/api/wallet/:username", get(get_wallet))
@@ -482,6 +1451,20 @@ pub mod api {
             .route("/api/energy/report-activity", post(report_activity))
             .route("/api/astra/map", get(get_day_mohk_map))
             .route("/api/astra/deploy", post(deploy_organism))
+            .route("/api/astra/islands", get(get_islands))
+            .route("/api/astra/islands/evolve", post(evolve_islands))
+            .route("/api/fitness/validate", post(validate_fitness))
+            .route("/api/experiments", get(list_experiments))
+            .route("/api/experiments", post(create_experiment))
+            .route("/api/experiments/:name", get(get_experiment))
+            .route("/api/experiments/:name", delete(delete_experiment))
+            .route("/api/experiments/:name/evolve", post(evolve_experiment))
+            .route("/api/experiments/:name/checkpoints", get(list_experiment_checkpoints))
+            .route("/api/experiments/:name/checkpoints", post(checkpoint_experiment))
+            .route("/api/checkpoints", get(list_world_checkpoints))
+            .route("/api/forks", post(fork_experiment))
+            .route("/api/forks/compare", get(compare_forks))
+            .route("/api/quota", get(get_quota))
             .route("/api/register", post(register))
             .route("/api/login", post(login))
             .route("/api/noosphere/markets", get(get_markets))
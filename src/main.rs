@@ -1,14 +1,19 @@
-use omnixius::api::{self, AppState, HistoryPoint};
+use omnixius::api::{self, AppState, RunState};
 use omnixius::layers::l_minus_1_energy::EnergyService;
 use omnixius::layers::l0_quantum::L0QuantumMutator;
-use omnixius::layers::l1_chronos::L1ChronosFileStorage;
+use omnixius::layers::l1_chronos::{Compression, L1ChronosFileStorage, SnapshotCodec};
 use omnixius::layers::l1_economy::EconomyService;
+use omnixius::layers::l1_history::HistoryStore;
 use omnixius::layers::l2_academy::AcademyService;
 use omnixius::layers::l2_investments::InvestmentService;
 use omnixius::layers::l2_quests::QuestService;
 use omnixius::layers::l6_events::EventService;
 use omnixius::layers::l6_day_mohk::DayMohkService;
-use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::{PhoenixEngine, Organism, Dna, OrganismId};
+use omnixius::layers::l3_organisms::o4_day_mohk::phoenix_engine::{PhoenixEngine, Organism, Dna, OrganismId, EvolutionConfig, HistoryPoint};
+use omnixius::layers::l3_organisms::o4_day_mohk::fitness::GeneSumFitness;
+use omnixius::layers::l3_organisms::o4_day_mohk::rebirth::RebirthPolicy;
+use omnixius::layers::l3_organisms::o4_day_mohk::registry::{EngineRegistry, TenantQuota};
+use omnixius::layers::l3_organisms::o4_day_mohk::islands::{self, Island, IslandModel, MigrationConfig};
 use omnixius::layers::l4_oikoumene::auth::AuthService;
 use omnixius::layers::l4_oikoumene::social::SocialService;
 use omnixius::layers::l5_telesophy::CommunicationService;
@@ -35,16 +40,14 @@ async fn main() {
     // 2. Initialize Components
     let quantum = L0QuantumMutator::default();
     let storage_root = PathBuf::from("layers/L1_chronos/checkpoints");
-    let blockchain = L1ChronosFileStorage::new(storage_root);
+    // Compact checkpoints; JSON ones written by earlier versions still load.
+    let codec = SnapshotCodec::Binary { compression: Compression::Zstd };
+    let blockchain = L1ChronosFileStorage::new(storage_root.clone()).with_codec(codec);
     
     // 3. Initial Population
     let mut rng = rand::thread_rng();
     let population: Vec<Organism> = (0..12)
-        .map(|i| Organism {
-            id: OrganismId(i as u64),
-            dna: Dna::new_random(8, &mut rng),
-            fitness: 0.0,
-        })
+        .map(|i| Organism::new(OrganismId(i as u64), Dna::new_random(8, &mut rng)))
         .collect();
 
     // 4. Create Engine
@@ -52,8 +55,17 @@ async fn main() {
         "L3_organisms::O4_day_mohk",
         quantum,
         blockchain,
+        GeneSumFitness::default(),
         population
-    );
+    )
+    .with_config(EvolutionConfig {
+        elitism: 1,
+        // Genomes keep their 8 genes: gene-sum fitness would reward growth
+        // itself (see `examples/structural_growth.rs` for variable length).
+        // Rise from the last good checkpoint if the world collapses.
+        rebirth: RebirthPolicy { enabled: true, ..RebirthPolicy::default() },
+        ..EvolutionConfig::default()
+    });
     
     // 5. Create Services
     let auth = AuthService::new(pool.clone(), "SUPER_SECRET_HACKER_KEY_123".to_string()).await;
@@ -63,14 +75,40 @@ async fn main() {
     let social = SocialService::new(pool.clone()).await;
     let investments = InvestmentService::new(pool.clone()).await;
     let quests = QuestService::new(pool.clone()).await;
+    let history = HistoryStore::new(pool.clone()).await;
     let energy = EnergyService::new();
     let events = EventService::new();
     let day_mohk = DayMohkService::get_core_locations();
 
+    // One island per Day-Mohk territory, exchanging champions along a ring.
+    let island_seed: u64 = rand::random();
+    let island_list = day_mohk
+        .iter()
+        .enumerate()
+        .map(|(i, loc)| {
+            let population = (0..12)
+                .map(|j| Organism::new(OrganismId((i * 1000 + j) as u64), Dna::new_random(8, &mut rng)))
+                .collect();
+            let engine = PhoenixEngine::new(
+                "L3_organisms::O4_day_mohk",
+                L0QuantumMutator::default(),
+                L1ChronosFileStorage::new(storage_root.join("islands").join(&loc.id)).with_codec(codec),
+                GeneSumFitness::default(),
+                population,
+            )
+            .with_seed(islands::island_seed(island_seed, i));
+            Island { id: loc.id.clone(), engine }
+        })
+        .collect();
+    let island_model = IslandModel::new(island_list, MigrationConfig::default(), island_seed);
+
     // 6. Create Shared State
     let state = Arc::new(AppState {
         energy: Arc::new(Mutex::new(energy)),
         engine: Arc::new(Mutex::new(engine)),
+        islands: Arc::new(Mutex::new(island_model)),
+        history: Arc::new(history),
+        run: Arc::new(Mutex::new(RunState::default())),
         auth: Arc::new(auth),
         economy: Arc::new(economy),
         comms: Arc::new(comms),
@@ -80,8 +118,21 @@ async fn main() {
         quests: Arc::new(quests),
         events: Arc::new(Mutex::new(events)),
         day_mohk: Arc::new(Mutex::new(day_mohk)),
-        history: Arc::new(Mutex::new(Vec::new())),
         last_activity: Arc::new(Mutex::new(HashMap::new())),
+        registry: Arc::new(Mutex::new(EngineRegistry::new(TenantQuota::default()))),
+        storage: L1ChronosFileStorage::new(storage_root.clone()).with_codec(codec),
+        // Comma-separated usernames, e.g. OMNIXIUS_ADMINS=alice,bob
+        admins: std::env::var("OMNIXIUS_ADMINS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect(),
+        started_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("clock after 1970")
+            .as_secs(),
     });
 
     // 7. Background Auto-Evolution
@@ -90,23 +141,39 @@ async fn main() {
         let mut interval = time::interval(Duration::from_secs(30));
         loop {
             interval.tick().await;
-            let mut engine = state_for_task.engine.lock().unwrap();
-            let mut history = state_for_task.history.lock().unwrap();
-            let mut rng = rand::thread_rng();
-            
-            let pop_size = engine.population_size();
-            engine.evolve(&mut rng, pop_size);
+            // Guards must be out of scope before persisting (they are not `Send`).
+            let mut points: Vec<(String, HistoryPoint)> = Vec::new();
+            // A controlled run (`/api/run/start`) owns the world engine while active.
+            let run_active = state_for_task.run.lock().unwrap().is_running();
+            if !run_active {
+                let evolved = api::evolve_unlocked(&state_for_task.engine, None).await;
+                let engine = state_for_task.engine.lock().unwrap();
+                let best_f = engine.best_fitness();
+
+                println!("[Auto-Evolve] Gen {} complete. Best Fitness: {:.2}", engine.generation, best_f);
+                // Only persist a generation this tick committed.
+                if let Some(point) = engine.history.last().filter(|_| evolved) {
+                    points.push((api::history_run_id(api::WORLD_RUN_ID, state_for_task.started_at), point.clone()));
+                }
+            }
+            {
+                let mut model = state_for_task.islands.lock().unwrap();
+                for event in model.evolve() {
+                    println!("[Islands] Gen {}: {} -> {} ({} migrants)", event.generation, event.from, event.to, event.organism_ids.len());
+                }
+                for island in &model.islands {
+                    if let Some(point) = island.engine.history.last() {
+                        let name = format!("island/{}", island.id);
+                        points.push((api::history_run_id(&name, state_for_task.started_at), point.clone()));
+                    }
+                }
+            }
 
-            let mut best_f = 0.0;
-            for org in &mut engine.population {
-                org.fitness = org.dna.genes.iter().sum::<f32>() * 2.0;
-                if org.fitness > best_f { best_f = org.fitness; }
+            for (run_id, point) in points {
+                if let Err(e) = state_for_task.history.record(&run_id, &point).await {
+                    eprintln!("[History] failed to persist {run_id} gen {}: {e}", point.generation);
+                }
             }
-            
-            history.push(HistoryPoint { generation: engine.generation, best_fitness: best_f });
-            if history.len() > 50 { history.remove(0); }
-            
-            println!("[Auto-Evolve] Gen {} complete. Best Fitness: {:.2}", engine.generation, best_f);
         }
     });
 